@@ -0,0 +1,333 @@
+use proc_macro2::{Span, TokenStream};
+use quote::ToTokens;
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Ident, Lit, LitStr, Path, Token,
+};
+
+/// A single macro argument, either a flag, e.g. `dispatch`, or a key with a
+/// value, e.g. `variant = "UpdateAdmin"` or `store = TREASURY`
+pub struct Arg {
+    key: Ident,
+    value: Option<(Token![=], Value)>,
+}
+
+/// The value of a macro argument
+enum Value {
+    Lit(Lit),
+    Path(Path),
+}
+
+impl Parse for Arg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key = input.parse()?;
+        let value = if input.peek(Token![=]) {
+            let eq = input.parse()?;
+            let value = if input.peek(Lit) {
+                Value::Lit(input.parse()?)
+            } else {
+                Value::Path(input.parse()?)
+            };
+            Some((eq, value))
+        } else {
+            None
+        };
+
+        Ok(Arg {
+            key,
+            value,
+        })
+    }
+}
+
+impl ToTokens for Arg {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.key.to_tokens(tokens);
+        if let Some((eq, value)) = &self.value {
+            eq.to_tokens(tokens);
+            match value {
+                Value::Lit(lit) => lit.to_tokens(tokens),
+                Value::Path(path) => path.to_tokens(tokens),
+            }
+        }
+    }
+}
+
+/// The comma-separated arguments of a macro
+pub struct ArgList(Punctuated<Arg, Token![,]>);
+
+impl Parse for ArgList {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Punctuated::parse_terminated(input).map(ArgList)
+    }
+}
+
+/// Whether the execute variant wraps the action in a tuple or a struct
+pub enum Style {
+    /// `UpdateOwnership(Action)`
+    Tuple,
+
+    /// `UpdateOwnership { action: Action }`
+    Struct,
+}
+
+/// Arguments accepted by the ownership macros
+#[derive(Default)]
+pub struct Args {
+    /// Name of the inserted variant, e.g. `variant = "UpdateAdmin"`
+    pub variant: Option<Ident>,
+
+    /// Shape of the inserted variant, e.g. `style = "struct"`
+    pub style: Option<Style>,
+
+    /// Doc comment of the inserted variant, e.g. `doc = "Update the admin"`
+    pub doc: Option<LitStr>,
+
+    /// Whether the history query is inserted, i.e. `history`
+    pub history: bool,
+
+    /// Whether the recovery status query is inserted, i.e. `recovery`
+    pub recovery: bool,
+
+    /// Whether a method handling the inserted variants is generated, i.e.
+    /// `dispatch`
+    pub dispatch: bool,
+
+    /// The ownership store the generated method dispatches to, e.g.
+    /// `store = TREASURY`
+    pub store: Option<Path>,
+
+    /// Name of the ownership slot the variants are for, e.g. `slot = "treasury"`
+    pub slot: Option<LitStr>,
+}
+
+impl Args {
+    /// Parse the macro's arguments, rejecting any that isn't in `allowed`.
+    pub fn parse(args: ArgList, allowed: &[&str]) -> syn::Result<Self> {
+        let mut parsed = Args::default();
+        let mut history = None;
+
+        for arg in args.0 {
+            let key = arg.key.to_string();
+            if !allowed.contains(&key.as_str()) {
+                return Err(syn::Error::new_spanned(
+                    arg,
+                    format!("unknown argument, expected one of: {}", allowed.join(", ")),
+                ));
+            }
+
+            match (key.as_str(), &arg.value) {
+                ("history" | "recovery" | "dispatch", None) => {
+                    let flag = match key.as_str() {
+                        "history" => &mut parsed.history,
+                        "recovery" => &mut parsed.recovery,
+                        _ => &mut parsed.dispatch,
+                    };
+                    if *flag {
+                        return Err(duplicate(&arg, &key));
+                    }
+                    *flag = true;
+                    if key == "history" {
+                        history = Some(arg.key.clone());
+                    }
+                },
+                ("variant", Some((_, value))) => {
+                    let lit = string_literal(value)?;
+                    let mut variant = syn::parse_str::<Ident>(&lit.value()).map_err(|_| {
+                        syn::Error::new_spanned(lit, "variant must be a valid identifier")
+                    })?;
+                    variant.set_span(lit.span());
+                    if parsed.variant.replace(variant).is_some() {
+                        return Err(duplicate(&arg, &key));
+                    }
+                },
+                ("style", Some((_, value))) => {
+                    let lit = string_literal(value)?;
+                    let style = match lit.value().as_str() {
+                        "tuple" => Style::Tuple,
+                        "struct" => Style::Struct,
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                lit,
+                                r#"style must be either "tuple" or "struct""#,
+                            ));
+                        },
+                    };
+                    if parsed.style.replace(style).is_some() {
+                        return Err(duplicate(&arg, &key));
+                    }
+                },
+                ("slot", Some((_, value))) => {
+                    let lit = string_literal(value)?;
+                    let value = lit.value();
+                    let valid = value.split('_').all(|word| {
+                        word.starts_with(|c: char| c.is_ascii_lowercase())
+                            && word.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+                    });
+                    if !valid {
+                        return Err(syn::Error::new_spanned(
+                            lit,
+                            "slot must be a snake_case name, e.g. \"treasury\"",
+                        ));
+                    }
+                    if parsed.slot.replace(lit.clone()).is_some() {
+                        return Err(duplicate(&arg, &key));
+                    }
+                },
+                ("doc", Some((_, value))) => {
+                    if parsed.doc.replace(string_literal(value)?.clone()).is_some() {
+                        return Err(duplicate(&arg, &key));
+                    }
+                },
+                ("store", Some((_, value))) => {
+                    let Value::Path(store) = value else {
+                        return Err(syn::Error::new_spanned(
+                            &arg,
+                            "expected a path, e.g. `store = OWNERSHIP`",
+                        ));
+                    };
+                    if parsed.store.replace(store.clone()).is_some() {
+                        return Err(duplicate(&arg, &key));
+                    }
+                },
+                ("history" | "recovery" | "dispatch", _) => {
+                    return Err(syn::Error::new_spanned(&arg, format!("`{key}` takes no value")));
+                },
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        arg,
+                        format!("expected `{key} = \"...\"`"),
+                    ));
+                },
+            }
+        }
+
+        if let (Some(slot), Some(_)) = (&parsed.slot, &parsed.variant) {
+            return Err(syn::Error::new_spanned(
+                slot,
+                "`slot` and `variant` cannot be used together",
+            ));
+        }
+
+        match (&parsed.store, &parsed.slot) {
+            (Some(store), _) if !parsed.dispatch => {
+                return Err(syn::Error::new_spanned(store, "`store` requires `dispatch`"));
+            },
+            (None, Some(slot)) if parsed.dispatch => {
+                return Err(syn::Error::new_spanned(
+                    slot,
+                    "`dispatch` with `slot` requires `store`, naming the `OwnershipSlots`",
+                ));
+            },
+            // the default ownership doesn't record its history
+            (None, None) if parsed.dispatch && history.is_some() => {
+                return Err(syn::Error::new_spanned(
+                    history,
+                    "`dispatch` with `history` requires `store`, naming an `OwnershipStore` \
+                     that records its history",
+                ));
+            },
+            _ => {},
+        }
+
+        Ok(parsed)
+    }
+
+    /// Return the name of the method generated by `dispatch`, named after the
+    /// slot, if any, e.g. `handle_treasury_ownership` for `slot = "treasury"`.
+    pub fn dispatch_method(&self) -> Ident {
+        match &self.slot {
+            Some(slot) => Ident::new(&format!("handle_{}_ownership", slot.value()), slot.span()),
+            None => Ident::new("handle_ownership", Span::call_site()),
+        }
+    }
+
+    /// Return the name of the inserted variant, or the given default.
+    ///
+    /// If a slot is given, the default is named after it, with `{}` replaced
+    /// by the slot's name in PascalCase, e.g. `Update{}Ownership` becomes
+    /// `UpdateTreasuryOwnership` for `slot = "treasury"`.
+    pub fn variant_or(&self, default: &str) -> Ident {
+        self.name_or("{}", default)
+    }
+
+    /// Return the name of an additional inserted variant.
+    ///
+    /// If the variant's name is given, `{}` in `named` is replaced by it, e.g.
+    /// `Is{}` becomes `IsAdmin` for `variant = "Admin"`. Otherwise, the name
+    /// is derived from `default` as in [`Args::variant_or`].
+    pub fn name_or(&self, named: &str, default: &str) -> Ident {
+        if let Some(variant) = &self.variant {
+            let name = variant.to_string();
+            let name = named.replace("{}", name.trim_start_matches("r#"));
+            return Ident::new(&name, variant.span());
+        }
+
+        match &self.slot {
+            Some(slot) => {
+                Ident::new(&default.replace("{}", &pascal_case(&slot.value())), slot.span())
+            },
+            None => Ident::new(&default.replace("{}", ""), Span::call_site()),
+        }
+    }
+}
+
+/// Arguments accepted by the `only_owner` macro
+#[derive(Default)]
+pub struct OnlyOwnerArgs {
+    /// The ownership store to check against, e.g. `store = TREASURY`
+    pub store: Option<Path>,
+}
+
+impl Parse for OnlyOwnerArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut parsed = OnlyOwnerArgs::default();
+
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            if key != "store" {
+                return Err(syn::Error::new_spanned(key, "unknown argument, expected: store"));
+            }
+
+            input.parse::<Token![=]>()?;
+            let store: Path = input.parse()?;
+            if parsed.store.replace(store).is_some() {
+                return Err(syn::Error::new_spanned(key, "duplicate argument `store`"));
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+fn pascal_case(snake: &str) -> String {
+    snake
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+                .collect::<String>()
+        })
+        .collect()
+}
+
+fn string_literal(value: &Value) -> syn::Result<&LitStr> {
+    match value {
+        Value::Lit(Lit::Str(lit)) => Ok(lit),
+        Value::Lit(lit) => Err(syn::Error::new_spanned(lit, "expected a string literal")),
+        Value::Path(path) => Err(syn::Error::new_spanned(path, "expected a string literal")),
+    }
+}
+
+fn duplicate(arg: &Arg, key: &str) -> syn::Error {
+    syn::Error::new_spanned(arg, format!("duplicate argument `{key}`"))
+}
@@ -1,6 +1,14 @@
+mod args;
+
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, AttributeArgs, DataEnum, DeriveInput};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, AttributeArgs, DataEnum, DeriveInput, FnArg, Ident, ItemFn, Pat, Signature,
+    Type,
+};
+
+use crate::args::{ArgList, Args, OnlyOwnerArgs, Style};
 
 /// Merges the variants of two enums.
 ///
@@ -77,25 +85,226 @@ fn merge_variants(metadata: TokenStream, left: TokenStream, right: TokenStream)
 /// }
 /// ```
 ///
+/// The inserted variant can be configured with the following arguments:
+///
+/// - `variant = "UpdateAdmin"` sets the variant's name;
+/// - `slot = "treasury"` names the variant after an ownership slot, e.g.
+///   `UpdateTreasuryOwnership`, for use with `OwnershipSlots`;
+/// - `style = "struct"` inserts `UpdateOwnership { action: Action }` instead
+///   of the default `style = "tuple"`;
+/// - `doc = "..."` sets the variant's doc comment;
+/// - `dispatch` also generates a method on the enum that handles the inserted
+///   variant, returning `None` for any other message:
+///
+/// ```rust
+/// impl ExecuteMsg {
+///     pub fn handle_ownership<E>(
+///         &self,
+///         deps: DepsMut,
+///         env: &Env,
+///         info: &MessageInfo,
+///     ) -> Option<Result<Response, E>>
+///     where
+///         E: From<OwnershipError>;
+/// }
+/// ```
+///
+///   so that the contract's `execute` entry point can start with:
+///
+/// ```rust
+/// if let Some(res) = msg.handle_ownership(deps.branch(), &env, &info) {
+///     return res;
+/// }
+/// ```
+///
+///   The method updates the default ownership, or the `OwnershipStore` given
+///   as `store = TREASURY`. With `slot`, `store` must name the
+///   `OwnershipSlots`, and the method is named after the slot, e.g.
+///   `handle_treasury_ownership`.
+///
 /// Note: `#[cw_ownable_execute]` must be applied _before_ `#[cw_serde]`.
 #[proc_macro_attribute]
 pub fn cw_ownable_execute(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(metadata as ArgList);
+    let args = match Args::parse(args, &["variant", "slot", "style", "doc", "dispatch", "store"]) {
+        Ok(args) => args,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let variant = args.variant_or("Update{}Ownership");
+    let dispatch = args.dispatch.then(|| {
+        let method = args.dispatch_method();
+        let pattern = match args.style {
+            Some(Style::Struct) => quote! { Self::#variant { action } },
+            Some(Style::Tuple) | None => quote! { Self::#variant(action) },
+        };
+        let update = match (&args.store, &args.slot) {
+            (Some(store), Some(slot)) => {
+                quote! { #store.execute_update_ownership(deps, env, info, #slot, action.clone()) }
+            },
+            (Some(store), None) => {
+                quote! { #store.execute_update_ownership(deps, env, info, action.clone()) }
+            },
+            (None, _) => {
+                quote! { ::cw_ownable::execute_update_ownership(deps, env, info, action.clone()) }
+            },
+        };
+        quote! {
+            /// Handle the ownership message by updating the ownership. Return
+            /// `None` for any other message.
+            #[allow(unreachable_patterns)]
+            pub fn #method<E>(
+                &self,
+                deps: ::cosmwasm_std::DepsMut,
+                env: &::cosmwasm_std::Env,
+                info: &::cosmwasm_std::MessageInfo,
+            ) -> ::std::option::Option<::std::result::Result<::cosmwasm_std::Response, E>>
+            where
+                E: ::std::convert::From<::cw_ownable::OwnershipError>,
+            {
+                match self {
+                    #pattern => Some(#update.map_err(E::from)),
+                    _ => None,
+                }
+            }
+        }
+    });
+    let doc = variant_doc(
+        &args,
+        "Update the ownership of the contract's `{}` slot.",
+        quote! {
+            /// Update the contract's ownership. The `action` to be provided
+            /// can be either to propose transferring ownership to an account,
+            /// accept, cancel or reject a pending ownership transfer, or
+            /// renounce the ownership permanently.
+        },
+    );
+    let fields = action_fields(&args, quote! { ::cw_ownable::Action });
+
+    let output = merge_variants(
+        TokenStream::new(),
+        input.clone(),
+        quote! {
+            enum Right {
+                #doc
+                #variant #fields,
+            }
+        }
+        .into(),
+    );
+    with_methods(output, input, dispatch)
+}
+
+/// Append the ownership-related sudo message variant to an enum, through which
+/// the chain's governance can force a change to the contract's ownership.
+///
+/// For example, apply the `cw_ownable_sudo` macro to the following enum:
+///
+/// ```rust
+/// use cosmwasm_schema::cw_serde;
+/// use cw_ownable::cw_ownable_sudo;
+///
+/// #[cw_ownable_sudo]
+/// #[cw_serde]
+/// enum SudoMsg {
+///     Foo {},
+/// }
+/// ```
+///
+/// Is equivalent to:
+///
+/// ```rust
+/// use cosmwasm_schema::cw_serde;
+/// use cw_ownable::SudoAction;
+///
+/// #[cw_serde]
+/// enum SudoMsg {
+///     UpdateOwnership(SudoAction),
+///     Foo {},
+/// }
+/// ```
+///
+/// The inserted variant accepts the same arguments as `cw_ownable_execute`.
+///
+/// Note: `#[cw_ownable_sudo]` must be applied _before_ `#[cw_serde]`.
+#[proc_macro_attribute]
+pub fn cw_ownable_sudo(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(metadata as ArgList);
+    let args = match Args::parse(args, &["variant", "slot", "style", "doc"]) {
+        Ok(args) => args,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let variant = args.variant_or("Update{}Ownership");
+    let doc = variant_doc(
+        &args,
+        "Force a change to the ownership of the contract's `{}` slot.",
+        quote! {
+            /// Force a change to the contract's ownership, bypassing the ownership
+            /// checks. The `action` to be provided can be either to appoint a new
+            /// owner, or to cancel the pending ownership transfer.
+        },
+    );
+    let fields = action_fields(&args, quote! { ::cw_ownable::SudoAction });
+
     merge_variants(
-        metadata,
+        TokenStream::new(),
         input,
         quote! {
             enum Right {
-                /// Update the contract's ownership. The `action` to be provided
-                /// can be either to propose transferring ownership to an account,
-                /// accept a pending ownership transfer, or renounce the ownership
-                /// permanently.
-                UpdateOwnership(::cw_ownable::Action),
+                #doc
+                #variant #fields,
             }
         }
         .into(),
     )
 }
 
+/// Append an `impl` block containing the given methods, if any, for the enum
+/// given as input.
+fn with_methods(
+    mut output: TokenStream,
+    input: TokenStream,
+    methods: Option<TokenStream2>,
+) -> TokenStream {
+    let Some(methods) = methods else {
+        return output;
+    };
+
+    let input: DeriveInput = parse_macro_input!(input);
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    output.extend(TokenStream::from(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #methods
+        }
+    }));
+    output
+}
+
+/// Return the doc comment of an inserted variant: the one given in the macro's
+/// arguments, or one naming the slot, with `{}` in `slot_doc` replaced by its
+/// name, or otherwise the default.
+fn variant_doc(args: &Args, slot_doc: &str, default: TokenStream2) -> TokenStream2 {
+    match (&args.doc, &args.slot) {
+        (Some(doc), _) => quote! { #[doc = #doc] },
+        (None, Some(slot)) => {
+            let doc = format!(" {}", slot_doc.replace("{}", &slot.value()));
+            quote! { #[doc = #doc] }
+        },
+        (None, None) => default,
+    }
+}
+
+/// Return the fields of an inserted variant wrapping the given action, in the
+/// style given in the macro's arguments.
+fn action_fields(args: &Args, action: TokenStream2) -> TokenStream2 {
+    match args.style {
+        Some(Style::Struct) => quote! { { action: #action } },
+        Some(Style::Tuple) | None => quote! { (#action) },
+    }
+}
+
 /// Append ownership-related query message variant(s) to an enum.
 ///
 /// For example, apply the `cw_ownable_query` macro to the following enum:
@@ -119,13 +328,19 @@ pub fn cw_ownable_execute(metadata: TokenStream, input: TokenStream) -> TokenStr
 ///
 /// ```rust
 /// use cosmwasm_schema::cw_serde;
-/// use cw_ownable::Ownership;
+/// use cw_ownable::{Ownership, PendingOwnership};
 ///
 /// #[cw_serde]
 /// #[derive(QueryResponses)]
 /// enum QueryMsg {
 ///     #[returns(Ownership<String>)]
 ///     Ownership {},
+///     #[returns(bool)]
+///     IsOwner {
+///         address: String,
+///     },
+///     #[returns(PendingOwnership)]
+///     PendingOwnership {},
 ///     #[returns(FooResponse)]
 ///     Foo {},
 ///     #[returns(BarResponse)]
@@ -133,19 +348,531 @@ pub fn cw_ownable_execute(metadata: TokenStream, input: TokenStream) -> TokenStr
 /// }
 /// ```
 ///
+/// If the ownership's history is recorded, apply `#[cw_ownable_query(history)]`
+/// to also insert the paginated history query:
+///
+/// ```rust
+/// #[returns(Vec<OwnershipChange>)]
+/// OwnershipHistory {
+///     start_after: Option<u64>,
+///     limit: Option<u32>,
+/// },
+/// ```
+///
+/// If the ownership can be recovered, apply `#[cw_ownable_query(recovery)]` to
+/// also insert the recovery status query:
+///
+/// ```rust
+/// #[returns(RecoveryStatus)]
+/// OwnershipRecoveryStatus {},
+/// ```
+///
+/// The inserted variant can be configured with the following arguments:
+///
+/// - `variant = "Admin"` sets the variant's name; the other queries are named
+///   after it, i.e. `IsAdmin`, `PendingAdmin`, `AdminHistory` and
+///   `AdminRecoveryStatus`;
+/// - `slot = "treasury"` names the variants after an ownership slot, i.e.
+///   `TreasuryOwnership`, `IsTreasuryOwner`, `PendingTreasuryOwnership` and
+///   `TreasuryOwnershipHistory` and `TreasuryOwnershipRecoveryStatus`, for use
+///   with `OwnershipSlots`;
+/// - `doc = "..."` sets the variant's doc comment;
+/// - `dispatch` also generates a method on the enum that handles the inserted
+///   variants, returning `None` for any other query:
+///
+/// ```rust
+/// impl QueryMsg {
+///     pub fn handle_ownership(&self, deps: Deps, env: &Env) -> Option<StdResult<Binary>>;
+/// }
+/// ```
+///
+///   so that the contract's `query` entry point can start with:
+///
+/// ```rust
+/// if let Some(res) = msg.handle_ownership(deps, &env) {
+///     return res;
+/// }
+/// ```
+///
+///   `store` and `slot` are handled the same as by `cw_ownable_execute`. With
+///   `history`, `store` is required, naming an `OwnershipStore` that records
+///   its history.
+///
 /// Note: `#[cw_ownable_query]` must be applied _before_ `#[cw_serde]`.
 #[proc_macro_attribute]
 pub fn cw_ownable_query(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(metadata as ArgList);
+    let args = match Args::parse(
+        args,
+        &["variant", "slot", "doc", "history", "recovery", "dispatch", "store"],
+    ) {
+        Ok(args) => args,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let variant = args.variant_or("{}Ownership");
+    let doc = variant_doc(
+        &args,
+        "Query the ownership of the contract's `{}` slot",
+        quote! {
+            /// Query the contract's ownership information
+        },
+    );
+
+    let is_owner = args.name_or("Is{}", "Is{}Owner");
+    let pending = args.name_or("Pending{}", "Pending{}Ownership");
+
+    let history = format_ident!("{}History", variant);
+    let recovery = format_ident!("{}RecoveryStatus", variant);
+
+    let history_variant = args.history.then(|| {
+        let variant = &history;
+        quote! {
+            /// Query the changes made to the contract's ownership, in the order
+            /// they were made
+            #[returns(Vec<::cw_ownable::OwnershipChange>)]
+            #variant {
+                start_after: Option<u64>,
+                limit: Option<u32>,
+            },
+        }
+    });
+
+    let recovery_variant = args.recovery.then(|| {
+        let variant = &recovery;
+        quote! {
+            /// Query the status of the ownership's recovery, and whether the
+            /// recovery address can currently claim the ownership
+            #[returns(::cw_ownable::RecoveryStatus)]
+            #variant {},
+        }
+    });
+
+    let dispatch = args.dispatch.then(|| {
+        let method = args.dispatch_method();
+        let history_arm = args.history.then(|| {
+            quote! {
+                Self::#history { start_after, limit } => {
+                    ::cw_ownable::OwnershipQuery::OwnershipHistory {
+                        start_after: *start_after,
+                        limit: *limit,
+                    }
+                },
+            }
+        });
+        let recovery_arm = args.recovery.then(|| {
+            quote! {
+                Self::#recovery {} => ::cw_ownable::OwnershipQuery::OwnershipRecoveryStatus {},
+            }
+        });
+        let query = match (&args.store, &args.slot) {
+            (Some(store), Some(slot)) => quote! { #store.query_ownership(deps, env, #slot, query) },
+            (Some(store), None) => quote! { #store.query_ownership(deps, env, query) },
+            (None, _) => quote! { ::cw_ownable::query_ownership(deps, env, query) },
+        };
+        quote! {
+            /// Handle the ownership query, serializing the response. Return
+            /// `None` for any other query.
+            #[allow(unreachable_patterns)]
+            pub fn #method(
+                &self,
+                deps: ::cosmwasm_std::Deps,
+                env: &::cosmwasm_std::Env,
+            ) -> ::std::option::Option<::cosmwasm_std::StdResult<::cosmwasm_std::Binary>> {
+                let query = match self {
+                    Self::#variant {} => ::cw_ownable::OwnershipQuery::Ownership {},
+                    Self::#is_owner { address } => ::cw_ownable::OwnershipQuery::IsOwner {
+                        address: address.clone(),
+                    },
+                    Self::#pending {} => ::cw_ownable::OwnershipQuery::PendingOwnership {},
+                    #history_arm
+                    #recovery_arm
+                    _ => return None,
+                };
+                Some(#query)
+            }
+        }
+    });
+
+    let output = merge_variants(
+        TokenStream::new(),
+        input.clone(),
+        quote! {
+            enum Right {
+                #doc
+                #[returns(::cw_ownable::Ownership<String>)]
+                #variant {},
+
+                /// Query whether the given address is the contract's current owner
+                #[returns(bool)]
+                #is_owner {
+                    address: String,
+                },
+
+                /// Query the pending ownership transfer, and whether it can
+                /// currently be accepted
+                #[returns(::cw_ownable::PendingOwnership)]
+                #pending {},
+
+                #history_variant
+
+                #recovery_variant
+            }
+        }
+        .into(),
+    );
+    with_methods(output, input, dispatch)
+}
+
+/// Append role-related execute message variant(s) to an enum.
+///
+/// For example, apply the `cw_roles_execute` macro to the following enum:
+///
+/// ```rust
+/// use cosmwasm_schema::cw_serde;
+/// use cw_ownable::cw_roles_execute;
+///
+/// #[cw_roles_execute]
+/// #[cw_serde]
+/// enum ExecuteMsg {
+///     Foo {},
+///     Bar {},
+/// }
+/// ```
+///
+/// Is equivalent to:
+///
+/// ```rust
+/// use cosmwasm_schema::cw_serde;
+/// use cw_ownable::RoleAction;
+///
+/// #[cw_serde]
+/// enum ExecuteMsg {
+///     UpdateRoles(RoleAction),
+///     Foo {},
+///     Bar {},
+/// }
+/// ```
+///
+/// Note: `#[cw_roles_execute]` must be applied _before_ `#[cw_serde]`.
+#[proc_macro_attribute]
+pub fn cw_roles_execute(metadata: TokenStream, input: TokenStream) -> TokenStream {
     merge_variants(
         metadata,
         input,
         quote! {
             enum Right {
-                /// Query the contract's ownership information
-                #[returns(::cw_ownable::Ownership<String>)]
-                Ownership {},
+                /// Update the contract's role assignments. The `action` to be
+                /// provided can be either to grant a role to an account, revoke
+                /// a role from an account, or renounce a role held by the caller.
+                UpdateRoles(::cw_ownable::RoleAction),
+            }
+        }
+        .into(),
+    )
+}
+
+/// Append role-related query message variant(s) to an enum.
+///
+/// For example, apply the `cw_roles_query` macro to the following enum:
+///
+/// ```rust
+/// use cosmwasm_schema::{cw_serde, QueryResponses};
+/// use cw_ownable::cw_roles_query;
+///
+/// #[cw_roles_query]
+/// #[cw_serde]
+/// #[derive(QueryResponses)]
+/// enum QueryMsg {
+///     #[returns(FooResponse)]
+///     Foo {},
+///     #[returns(BarResponse)]
+///     Bar {},
+/// }
+/// ```
+///
+/// Is equivalent to:
+///
+/// ```rust
+/// use cosmwasm_schema::cw_serde;
+///
+/// #[cw_serde]
+/// #[derive(QueryResponses)]
+/// enum QueryMsg {
+///     #[returns(bool)]
+///     HasRole {
+///         role: String,
+///         address: String,
+///     },
+///     #[returns(Vec<String>)]
+///     RoleMembers {
+///         role: String,
+///         start_after: Option<String>,
+///         limit: Option<u32>,
+///     },
+///     #[returns(FooResponse)]
+///     Foo {},
+///     #[returns(BarResponse)]
+///     Bar {},
+/// }
+/// ```
+///
+/// Note: `#[cw_roles_query]` must be applied _before_ `#[cw_serde]`.
+#[proc_macro_attribute]
+pub fn cw_roles_query(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    merge_variants(
+        metadata,
+        input,
+        quote! {
+            enum Right {
+                /// Query whether an account has been granted a role
+                #[returns(bool)]
+                HasRole {
+                    role: String,
+                    address: String,
+                },
+
+                /// Enumerate the accounts that have been granted a role
+                #[returns(Vec<String>)]
+                RoleMembers {
+                    role: String,
+                    start_after: Option<String>,
+                    limit: Option<u32>,
+                },
+            }
+        }
+        .into(),
+    )
+}
+
+/// Append pause-related execute message variant(s) to an enum.
+///
+/// For example, apply the `cw_pausable_execute` macro to the following enum:
+///
+/// ```rust
+/// use cosmwasm_schema::cw_serde;
+/// use cw_ownable::cw_pausable_execute;
+///
+/// #[cw_pausable_execute]
+/// #[cw_serde]
+/// enum ExecuteMsg {
+///     Foo {},
+///     Bar {},
+/// }
+/// ```
+///
+/// Is equivalent to:
+///
+/// ```rust
+/// use cosmwasm_schema::cw_serde;
+/// use cw_ownable::PauseAction;
+///
+/// #[cw_serde]
+/// enum ExecuteMsg {
+///     UpdatePause(PauseAction),
+///     Foo {},
+///     Bar {},
+/// }
+/// ```
+///
+/// Note: `#[cw_pausable_execute]` must be applied _before_ `#[cw_serde]`.
+#[proc_macro_attribute]
+pub fn cw_pausable_execute(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    merge_variants(
+        metadata,
+        input,
+        quote! {
+            enum Right {
+                /// Pause or unpause the contract, or appoint an account who
+                /// may do so besides the owner.
+                UpdatePause(::cw_ownable::PauseAction),
             }
         }
         .into(),
     )
 }
+
+/// Append pause-related query message variant(s) to an enum.
+///
+/// For example, apply the `cw_pausable_query` macro to the following enum:
+///
+/// ```rust
+/// use cosmwasm_schema::{cw_serde, QueryResponses};
+/// use cw_ownable::cw_pausable_query;
+///
+/// #[cw_pausable_query]
+/// #[cw_serde]
+/// #[derive(QueryResponses)]
+/// enum QueryMsg {
+///     #[returns(FooResponse)]
+///     Foo {},
+///     #[returns(BarResponse)]
+///     Bar {},
+/// }
+/// ```
+///
+/// Is equivalent to:
+///
+/// ```rust
+/// use cosmwasm_schema::cw_serde;
+/// use cw_ownable::PauseInfo;
+///
+/// #[cw_serde]
+/// #[derive(QueryResponses)]
+/// enum QueryMsg {
+///     #[returns(PauseInfo<String>)]
+///     PauseInfo {},
+///     #[returns(FooResponse)]
+///     Foo {},
+///     #[returns(BarResponse)]
+///     Bar {},
+/// }
+/// ```
+///
+/// Note: `#[cw_pausable_query]` must be applied _before_ `#[cw_serde]`.
+#[proc_macro_attribute]
+pub fn cw_pausable_query(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    merge_variants(
+        metadata,
+        input,
+        quote! {
+            enum Right {
+                /// Query the contract's pause information
+                #[returns(::cw_ownable::PauseInfo<String>)]
+                PauseInfo {},
+            }
+        }
+        .into(),
+    )
+}
+
+/// Assert that the sender is the contract's owner before running an execute
+/// handler.
+///
+/// For example, apply the `only_owner` macro to the following function:
+///
+/// ```rust
+/// use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+/// use cw_ownable::only_owner;
+///
+/// #[only_owner]
+/// fn execute_foo(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+///     // ...
+/// }
+/// ```
+///
+/// Is equivalent to:
+///
+/// ```rust
+/// use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+///
+/// fn execute_foo(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+///     cw_ownable::record_activity(deps.storage, &env.block, &info.sender)?;
+///     // ...
+/// }
+/// ```
+///
+/// If the handler takes an `Env`, the owner is checked at the current block, so
+/// once the ownership's lease has ended, the fallback owner is the one allowed
+/// through. If the ownership also has a recovery period, the call counts as the
+/// owner's activity, unless the handler only takes a `Deps`. Without an `Env`,
+/// the owner is checked as it's stored, using `assert_stored_owner`.
+///
+/// To check against another ownership, provide its store, e.g.
+/// `#[only_owner(store = TREASURY)]`.
+///
+/// The function must take a `DepsMut` (or `Deps`) and a `MessageInfo` (or
+/// `&MessageInfo`), optionally an `Env` (or `&Env`), and its error type must
+/// implement `From<OwnershipError>`.
+#[proc_macro_attribute]
+pub fn only_owner(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(metadata as OnlyOwnerArgs);
+    let mut func = parse_macro_input!(input as ItemFn);
+
+    // with mutable access to the storage, the owner's activity is recorded
+    let deps_mut = find_param(&func.sig, &["DepsMut"]);
+    let record_activity = deps_mut.is_some();
+
+    let deps = match deps_mut.or_else(|| find_param(&func.sig, &["Deps"])) {
+        Some(Ok(deps)) => deps.clone(),
+        Some(Err(err)) => return err.to_compile_error().into(),
+        None => {
+            return syn::Error::new_spanned(
+                &func.sig,
+                "`#[only_owner]` requires a `DepsMut` or `Deps` parameter",
+            )
+            .to_compile_error()
+            .into();
+        },
+    };
+
+    // without the current block, the owner is checked as it's stored
+    let env = match find_param(&func.sig, &["Env"]) {
+        Some(Ok(env)) => Some(env.clone()),
+        Some(Err(err)) => return err.to_compile_error().into(),
+        None => None,
+    };
+
+    let info = match find_param(&func.sig, &["MessageInfo"]) {
+        Some(Ok(info)) => info.clone(),
+        Some(Err(err)) => return err.to_compile_error().into(),
+        None => {
+            return syn::Error::new_spanned(
+                &func.sig,
+                "`#[only_owner]` requires a `MessageInfo` parameter",
+            )
+            .to_compile_error()
+            .into();
+        },
+    };
+
+    // the store's methods and the crate's functions share their names
+    let prefix = match &args.store {
+        Some(store) => quote! { #store. },
+        None => quote! { ::cw_ownable:: },
+    };
+    let check = match (env, record_activity) {
+        (Some(env), true) => quote! {
+            #prefix record_activity(#deps.storage, &#env.block, &#info.sender)?;
+        },
+        (Some(env), false) => quote! {
+            #prefix assert_owner(#deps.storage, &#env.block, &#info.sender)?;
+        },
+        (None, _) => quote! {
+            #prefix assert_stored_owner(#deps.storage, &#info.sender)?;
+        },
+    };
+    func.block.stmts.insert(0, syn::parse_quote!(#check));
+
+    quote! { #func }.into()
+}
+
+/// Find the parameter whose type is, or is a reference to, one of the given
+/// types, and return the name it's bound to.
+fn find_param<'a>(sig: &'a Signature, types: &[&str]) -> Option<syn::Result<&'a Ident>> {
+    sig.inputs.iter().find_map(|input| {
+        let FnArg::Typed(pat_type) = input else {
+            return None;
+        };
+
+        let ty = match &*pat_type.ty {
+            Type::Reference(reference) => &*reference.elem,
+            ty => ty,
+        };
+        let Type::Path(path) = ty else {
+            return None;
+        };
+        let segment = path.path.segments.last()?;
+        if !types.iter().any(|ty| segment.ident == ty) {
+            return None;
+        }
+
+        Some(match &*pat_type.pat {
+            Pat::Ident(pat) => Ok(&pat.ident),
+            pat => Err(syn::Error::new_spanned(
+                pat,
+                format!("the `{}` parameter must be bound to a name", segment.ident),
+            )),
+        })
+    })
+}
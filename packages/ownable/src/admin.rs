@@ -0,0 +1,185 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, QuerierWrapper, StdResult, Storage, WasmMsg};
+
+use crate::{Ownership, OwnershipStore};
+
+/// The wasm-level admin of a contract compared against its owner
+#[cw_serde]
+pub struct AdminSyncStatus {
+    /// The contract's wasm-level admin, who can migrate the contract.
+    /// `None` if the contract has no admin.
+    pub admin: Option<Addr>,
+
+    /// The contract's current owner.
+    /// `None` if the ownership has been renounced.
+    pub owner: Option<Addr>,
+}
+
+impl AdminSyncStatus {
+    /// Return true if the admin and the owner are the same account, or if the
+    /// contract has neither.
+    pub fn in_sync(&self) -> bool {
+        self.admin == self.owner
+    }
+}
+
+/// Return the message that updates the contract's wasm-level admin to match
+/// its new owner, if the owner has changed, e.g. when a transfer is accepted
+/// or the ownership is renounced.
+pub fn admin_sync_msg(
+    contract: &Addr,
+    previous_ownership: &Ownership<Addr>,
+    new_ownership: &Ownership<Addr>,
+) -> Option<WasmMsg> {
+    if previous_ownership.owner == new_ownership.owner {
+        return None;
+    }
+
+    Some(match &new_ownership.owner {
+        Some(owner) => WasmMsg::UpdateAdmin {
+            contract_addr: contract.into(),
+            admin: owner.into(),
+        },
+        None => WasmMsg::ClearAdmin {
+            contract_addr: contract.into(),
+        },
+    })
+}
+
+impl OwnershipStore {
+    /// Compare the contract's wasm-level admin against its current owner.
+    pub fn query_admin_sync(
+        &self,
+        querier: QuerierWrapper,
+        storage: &dyn Storage,
+        contract: &Addr,
+    ) -> StdResult<AdminSyncStatus> {
+        let info = querier.query_wasm_contract_info(contract)?;
+        // the admin is only updated by an action, so it's compared against the
+        // owner as it's stored, rather than the fallback of an ended lease
+        let ownership = self.item.load(storage)?;
+
+        Ok(AdminSyncStatus {
+            admin: info.admin,
+            owner: ownership.owner,
+        })
+    }
+}
+
+//------------------------------------------------------------------------------
+// Tests
+//------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{
+        testing::{message_info, mock_dependencies, mock_env},
+        to_json_binary, ContractInfoResponse, ContractResult, CosmosMsg, SystemResult, WasmQuery,
+    };
+
+    use super::*;
+    use crate::{Action, OWNERSHIP_KEY};
+
+    const OWNERSHIP: OwnershipStore = OwnershipStore::new(OWNERSHIP_KEY).with_admin_sync();
+
+    #[test]
+    fn syncing_admin_with_owner() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let larry = deps.api.addr_make("larry");
+        let jake = deps.api.addr_make("jake");
+
+        OWNERSHIP.initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str())).unwrap();
+
+        // proposing a transfer doesn't change the admin
+        let res = OWNERSHIP
+            .execute_update_ownership(
+                deps.as_mut(),
+                &env,
+                &message_info(&larry, &[]),
+                Action::TransferOwnership {
+                    new_owner: jake.to_string(),
+                    expiry: None,
+                    expires_in: None,
+                    delay: None,
+                    pubkey: None,
+                    price: None,
+                },
+            )
+            .unwrap();
+        assert!(res.messages.is_empty());
+
+        // accepting the transfer updates the admin
+        let res = OWNERSHIP
+            .execute_update_ownership(
+                deps.as_mut(),
+                &env,
+                &message_info(&jake, &[]),
+                Action::AcceptOwnership,
+            )
+            .unwrap();
+        assert_eq!(res.messages.len(), 1);
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Wasm(WasmMsg::UpdateAdmin {
+                contract_addr: env.contract.address.to_string(),
+                admin: jake.to_string(),
+            }),
+        );
+
+        // renouncing the ownership clears the admin
+        let res = OWNERSHIP
+            .execute_update_ownership(
+                deps.as_mut(),
+                &env,
+                &message_info(&jake, &[]),
+                Action::RenounceOwnership,
+            )
+            .unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Wasm(WasmMsg::ClearAdmin {
+                contract_addr: env.contract.address.to_string(),
+            }),
+        );
+    }
+
+    #[test]
+    fn querying_admin_sync() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let larry = deps.api.addr_make("larry");
+        let jake = deps.api.addr_make("jake");
+
+        OWNERSHIP.initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str())).unwrap();
+
+        let admin = jake.clone();
+        deps.querier.update_wasm(move |query| match query {
+            WasmQuery::ContractInfo {
+                ..
+            } => {
+                let info = ContractInfoResponse::new(
+                    1,
+                    Addr::unchecked("creator"),
+                    Some(admin.clone()),
+                    false,
+                    None,
+                );
+                SystemResult::Ok(ContractResult::Ok(to_json_binary(&info).unwrap()))
+            },
+            _ => unimplemented!(),
+        });
+
+        let status = OWNERSHIP
+            .query_admin_sync(deps.as_ref().querier, deps.as_ref().storage, &env.contract.address)
+            .unwrap();
+        assert_eq!(
+            status,
+            AdminSyncStatus {
+                admin: Some(jake),
+                owner: Some(larry),
+            },
+        );
+        assert!(!status.in_sync());
+    }
+}
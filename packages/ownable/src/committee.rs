@@ -0,0 +1,646 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Api, DepsMut, Env, MessageInfo, Order, Response, StdResult, Storage};
+use cw_address_like::AddressLike;
+use cw_storage_plus::{Bound, Item, Map};
+
+use crate::{Action, Expiration, Ownership, OwnershipError, OwnershipStore};
+
+/// A set of accounts that collectively own the contract
+#[cw_serde]
+pub struct Committee<T: AddressLike> {
+    /// The accounts that may propose and approve ownership actions
+    pub members: Vec<T>,
+
+    /// The number of approvals a proposal needs before it's applied
+    pub threshold: u32,
+}
+
+/// A change that a proposal makes once approved
+#[cw_serde]
+pub enum ProposalAction {
+    /// Apply an action to the ownership, as if sent by the contract itself.
+    UpdateOwnership(Action),
+
+    /// Replace the committee's members and threshold, e.g. to remove a member
+    /// who has lost its key.
+    UpdateCommittee(Committee<String>),
+}
+
+/// A change proposed by a committee member
+#[cw_serde]
+pub struct Proposal {
+    /// Sequential identifier of the proposal, starting from 1
+    pub id: u64,
+
+    /// The change to be made once approved
+    pub action: ProposalAction,
+
+    /// The member who proposed the action
+    pub proposer: Addr,
+
+    /// The accounts who have approved the proposal, including the proposer.
+    /// Only those who are still members count towards the threshold.
+    pub approvals: Vec<Addr>,
+
+    /// The deadline for the proposal to reach the threshold
+    pub expiry: Expiration,
+
+    /// Whether the proposal has reached the threshold and been applied
+    pub executed: bool,
+}
+
+impl Committee<String> {
+    /// Validate the committee's members, removing duplicates, and check that
+    /// its threshold can be reached.
+    pub fn validate(&self, api: &dyn Api) -> Result<Committee<Addr>, OwnershipError> {
+        let mut members = self
+            .members
+            .iter()
+            .map(|member| api.addr_validate(member))
+            .collect::<StdResult<Vec<_>>>()?;
+        members.sort();
+        members.dedup();
+
+        if self.threshold == 0 || self.threshold as usize > members.len() {
+            return Err(OwnershipError::InvalidThreshold);
+        }
+
+        Ok(Committee {
+            members,
+            threshold: self.threshold,
+        })
+    }
+}
+
+/// Actions that can be taken by the committee's members
+#[cw_serde]
+pub enum CommitteeAction {
+    /// Propose an ownership action or a change to the committee, optionally
+    /// with an expiry time. The proposal is approved by the proposer.
+    ///
+    /// Can only be called by a committee member.
+    Propose {
+        action: ProposalAction,
+        expiry: Option<Expiration>,
+    },
+
+    /// Approve a proposal. Once the proposal has as many approvals as the
+    /// threshold, its change is made.
+    ///
+    /// Can only be called by a committee member.
+    Approve {
+        proposal_id: u64,
+    },
+}
+
+/// An ownership held by a committee of accounts, rather than a single account.
+///
+/// The committee acts through the contract's own address: it is recorded as
+/// the owner of the underlying [`OwnershipStore`], and approved proposals are
+/// applied on its behalf. Consequently, `assert_owner` on the underlying store
+/// accepts the contract's own address as the result of an approved proposal.
+pub struct CommitteeStore {
+    pub ownership: OwnershipStore,
+    pub committee: Item<Committee<Addr>>,
+    pub proposals: Map<u64, Proposal>,
+}
+
+impl CommitteeStore {
+    /// Create a committee store owning the given ownership store, which should
+    /// be configured the same way as the store the contract uses, e.g. by
+    /// passing the same constant.
+    pub const fn new(
+        ownership: OwnershipStore,
+        committee_key: &'static str,
+        proposals_key: &'static str,
+    ) -> Self {
+        Self {
+            ownership,
+            committee: Item::new(committee_key),
+            proposals: Map::new(proposals_key),
+        }
+    }
+
+    /// Set the given committee as the contract owner.
+    ///
+    /// This function is only intended to be used only during contract instantiation.
+    pub fn initialize_committee(
+        &self,
+        storage: &mut dyn Storage,
+        api: &dyn Api,
+        env: &Env,
+        committee: Committee<String>,
+    ) -> Result<Ownership<Addr>, OwnershipError> {
+        self.committee.save(storage, &committee.validate(api)?)?;
+
+        let ownership = Ownership::new(Some(env.contract.address.clone()));
+        self.ownership.item.save(storage, &ownership)?;
+
+        Ok(ownership)
+    }
+
+    /// Replace the committee's members and threshold, bypassing its approval,
+    /// e.g. when too many members have lost their keys to reach the threshold.
+    ///
+    /// This function is only intended to be used in the contract's `sudo` or
+    /// `migrate` entry point. Otherwise, members change the committee through
+    /// an approved [`ProposalAction::UpdateCommittee`] proposal.
+    pub fn update_committee(
+        &self,
+        storage: &mut dyn Storage,
+        api: &dyn Api,
+        committee: Committee<String>,
+    ) -> Result<Committee<Addr>, OwnershipError> {
+        let committee = committee.validate(api)?;
+        self.committee.save(storage, &committee)?;
+        Ok(committee)
+    }
+
+    /// Return Ok(true) if the given address is a member of the committee.
+    /// Return Err if fails to load committee info from storage.
+    pub fn is_member(&self, store: &dyn Storage, addr: &Addr) -> StdResult<bool> {
+        let committee = self.committee.load(store)?;
+        Ok(committee.members.contains(addr))
+    }
+
+    /// Propose or approve a change based on the given committee action.
+    /// Return a response with the proposal's id and whether it was executed.
+    ///
+    /// If the proposal reaches the threshold, its change is made. An ownership
+    /// action is applied through [`OwnershipStore::execute_update_ownership`]
+    /// as if sent by the contract itself, and the response includes the event
+    /// and messages it returns. The funds sent along by the member whose
+    /// approval executes the proposal are used to pay for a priced transfer.
+    pub fn execute_update_ownership(
+        &self,
+        mut deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        action: CommitteeAction,
+    ) -> Result<Response, OwnershipError> {
+        let sender = &info.sender;
+        let committee = self.committee.load(deps.storage)?;
+
+        // the sender must be a committee member
+        if !committee.members.contains(sender) {
+            return Err(OwnershipError::NotCommitteeMember);
+        }
+
+        let mut proposal = match action {
+            CommitteeAction::Propose {
+                action,
+                expiry,
+            } => {
+                // a new committee must be valid when it's proposed
+                if let ProposalAction::UpdateCommittee(committee) = &action {
+                    committee.validate(deps.api)?;
+                }
+
+                let last_id =
+                    self.proposals.keys(deps.storage, None, None, Order::Descending).next();
+                Proposal {
+                    id: last_id.transpose()?.unwrap_or(0) + 1,
+                    action,
+                    proposer: sender.clone(),
+                    approvals: vec![sender.clone()],
+                    expiry: expiry.unwrap_or_default(),
+                    executed: false,
+                }
+            },
+            CommitteeAction::Approve {
+                proposal_id,
+            } => {
+                let Some(mut proposal) = self.proposals.may_load(deps.storage, proposal_id)? else {
+                    return Err(OwnershipError::ProposalNotFound(proposal_id));
+                };
+
+                if proposal.executed {
+                    return Err(OwnershipError::ProposalExecuted(proposal_id));
+                }
+
+                if proposal.expiry.is_expired(&env.block) {
+                    return Err(OwnershipError::ProposalExpired(proposal_id));
+                }
+
+                if proposal.approvals.contains(sender) {
+                    return Err(OwnershipError::AlreadyApproved(proposal_id));
+                }
+
+                proposal.approvals.push(sender.clone());
+                proposal
+            },
+        };
+
+        // approvals by accounts who have since been removed from the committee
+        // don't count
+        let approvals =
+            proposal.approvals.iter().filter(|addr| committee.members.contains(addr)).count();
+
+        let mut res = Response::new();
+        if approvals >= committee.threshold as usize {
+            proposal.executed = true;
+            match proposal.action.clone() {
+                ProposalAction::UpdateOwnership(action) => {
+                    let info = MessageInfo {
+                        sender: env.contract.address.clone(),
+                        funds: info.funds.clone(),
+                    };
+                    let ownership_res = self.ownership.execute_update_ownership(
+                        deps.branch(),
+                        env,
+                        &info,
+                        action,
+                    )?;
+                    res = res
+                        .add_attributes(ownership_res.attributes)
+                        .add_events(ownership_res.events)
+                        .add_submessages(ownership_res.messages);
+                },
+                ProposalAction::UpdateCommittee(committee) => {
+                    self.committee.save(deps.storage, &committee.validate(deps.api)?)?;
+                },
+            }
+        }
+
+        self.proposals.save(deps.storage, proposal.id, &proposal)?;
+
+        Ok(res
+            .add_attribute("proposal_id", proposal.id.to_string())
+            .add_attribute("executed", proposal.executed.to_string()))
+    }
+
+    /// Get the current committee.
+    pub fn get_committee(&self, storage: &dyn Storage) -> StdResult<Committee<Addr>> {
+        self.committee.load(storage)
+    }
+
+    /// Get a proposal by its id.
+    pub fn get_proposal(&self, storage: &dyn Storage, id: u64) -> StdResult<Proposal> {
+        self.proposals.load(storage, id)
+    }
+
+    /// Return the proposals, in the order they were made.
+    pub fn query_proposals(
+        &self,
+        storage: &dyn Storage,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<Proposal>> {
+        let start = start_after.map(Bound::exclusive);
+        cw_paginate::paginate_map(&self.proposals, storage, start, limit, |_, proposal| {
+            Ok(proposal)
+        })
+    }
+}
+
+//------------------------------------------------------------------------------
+// Tests
+//------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+
+    use super::*;
+    use crate::testing::mock_addresses;
+
+    const COMMITTEE: CommitteeStore =
+        CommitteeStore::new(OwnershipStore::new("ownership"), "committee", "proposals");
+
+    #[test]
+    fn initializing_committee() {
+        let mut deps = mock_dependencies();
+        let [larry, jake, pumpkin] = mock_addresses(&deps.api);
+        let env = mock_env();
+
+        // threshold must be reachable
+        for threshold in [0, 3] {
+            let err = COMMITTEE
+                .initialize_committee(
+                    &mut deps.storage,
+                    &deps.api,
+                    &env,
+                    Committee {
+                        members: vec![larry.to_string(), jake.to_string(), larry.to_string()],
+                        threshold,
+                    },
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::InvalidThreshold);
+        }
+
+        let ownership = COMMITTEE
+            .initialize_committee(
+                &mut deps.storage,
+                &deps.api,
+                &env,
+                Committee {
+                    members: vec![larry.to_string(), jake.to_string()],
+                    threshold: 2,
+                },
+            )
+            .unwrap();
+        assert_eq!(ownership.owner, Some(env.contract.address.clone()));
+
+        assert!(COMMITTEE.is_member(deps.as_ref().storage, &larry).unwrap());
+        assert!(!COMMITTEE.is_member(deps.as_ref().storage, &pumpkin).unwrap());
+        assert!(COMMITTEE
+            .ownership
+            .assert_owner(deps.as_ref().storage, &env.block, &env.contract.address)
+            .is_ok());
+        assert_eq!(
+            COMMITTEE
+                .ownership
+                .assert_owner(deps.as_ref().storage, &env.block, &larry)
+                .unwrap_err(),
+            OwnershipError::NotOwner,
+        );
+    }
+
+    #[test]
+    fn proposing_and_approving() {
+        let mut deps = mock_dependencies();
+        let [larry, jake, pumpkin] = mock_addresses(&deps.api);
+        let env = mock_env();
+
+        COMMITTEE
+            .initialize_committee(
+                &mut deps.storage,
+                &deps.api,
+                &env,
+                Committee {
+                    members: vec![larry.to_string(), jake.to_string(), pumpkin.to_string()],
+                    threshold: 2,
+                },
+            )
+            .unwrap();
+
+        let transfer = Action::TransferOwnership {
+            new_owner: pumpkin.to_string(),
+            expiry: None,
+            expires_in: None,
+            delay: None,
+            pubkey: None,
+            price: None,
+        };
+
+        // non-member cannot propose
+        {
+            let err = COMMITTEE
+                .execute_update_ownership(
+                    deps.as_mut(),
+                    &env,
+                    &message_info(&env.contract.address, &[]),
+                    CommitteeAction::Propose {
+                        action: ProposalAction::UpdateOwnership(transfer.clone()),
+                        expiry: None,
+                    },
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::NotCommitteeMember);
+        }
+
+        // member properly proposes; the proposal isn't applied until it
+        // reaches the threshold
+        {
+            let res = COMMITTEE
+                .execute_update_ownership(
+                    deps.as_mut(),
+                    &env,
+                    &message_info(&larry, &[]),
+                    CommitteeAction::Propose {
+                        action: ProposalAction::UpdateOwnership(transfer.clone()),
+                        expiry: Some(Expiration::AtHeight(env.block.height + 10)),
+                    },
+                )
+                .unwrap();
+            assert_eq!(res.attributes, [("proposal_id", "1"), ("executed", "false")]);
+            assert!(res.events.is_empty());
+
+            let proposal = COMMITTEE.get_proposal(deps.as_ref().storage, 1).unwrap();
+            assert_eq!(
+                proposal,
+                Proposal {
+                    id: 1,
+                    action: ProposalAction::UpdateOwnership(transfer.clone()),
+                    proposer: larry.clone(),
+                    approvals: vec![larry.clone()],
+                    expiry: Expiration::AtHeight(env.block.height + 10),
+                    executed: false,
+                },
+            );
+
+            let ownership =
+                COMMITTEE.ownership.get_ownership(deps.as_ref().storage, &env.block).unwrap();
+            assert_eq!(ownership.pending_owner, None);
+        }
+
+        // cannot approve twice
+        {
+            let err = COMMITTEE
+                .execute_update_ownership(
+                    deps.as_mut(),
+                    &env,
+                    &message_info(&larry, &[]),
+                    CommitteeAction::Approve {
+                        proposal_id: 1,
+                    },
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::AlreadyApproved(1));
+        }
+
+        // cannot approve a proposal that doesn't exist
+        {
+            let err = COMMITTEE
+                .execute_update_ownership(
+                    deps.as_mut(),
+                    &env,
+                    &message_info(&jake, &[]),
+                    CommitteeAction::Approve {
+                        proposal_id: 2,
+                    },
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::ProposalNotFound(2));
+        }
+
+        // cannot approve after the proposal has expired
+        {
+            let mut expired_env = mock_env();
+            expired_env.block.height += 10;
+
+            let err = COMMITTEE
+                .execute_update_ownership(
+                    deps.as_mut(),
+                    &expired_env,
+                    &message_info(&jake, &[]),
+                    CommitteeAction::Approve {
+                        proposal_id: 1,
+                    },
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::ProposalExpired(1));
+        }
+
+        // proposal is applied once it reaches the threshold, through the
+        // ownership's response-returning entry point
+        {
+            let res = COMMITTEE
+                .execute_update_ownership(
+                    deps.as_mut(),
+                    &env,
+                    &message_info(&jake, &[]),
+                    CommitteeAction::Approve {
+                        proposal_id: 1,
+                    },
+                )
+                .unwrap();
+            assert_eq!(res.attributes, [("proposal_id", "1"), ("executed", "true")]);
+            assert_eq!(res.events[0].ty, "ownership_transfer_proposed");
+
+            let proposal = COMMITTEE.get_proposal(deps.as_ref().storage, 1).unwrap();
+            assert!(proposal.executed);
+            assert_eq!(proposal.approvals, vec![larry, jake]);
+
+            let ownership =
+                COMMITTEE.ownership.get_ownership(deps.as_ref().storage, &env.block).unwrap();
+            assert_eq!(ownership.pending_owner, Some(pumpkin.clone()));
+        }
+
+        // cannot approve a proposal that has been applied
+        {
+            let err = COMMITTEE
+                .execute_update_ownership(
+                    deps.as_mut(),
+                    &env,
+                    &message_info(&pumpkin, &[]),
+                    CommitteeAction::Approve {
+                        proposal_id: 1,
+                    },
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::ProposalExecuted(1));
+        }
+
+        let proposals = COMMITTEE.query_proposals(deps.as_ref().storage, None, None).unwrap();
+        assert_eq!(proposals, vec![COMMITTEE.get_proposal(deps.as_ref().storage, 1).unwrap()]);
+    }
+
+    #[test]
+    fn updating_committee() {
+        let mut deps = mock_dependencies();
+        let [larry, jake, pumpkin] = mock_addresses(&deps.api);
+        let env = mock_env();
+
+        COMMITTEE
+            .initialize_committee(
+                &mut deps.storage,
+                &deps.api,
+                &env,
+                Committee {
+                    members: vec![larry.to_string(), jake.to_string(), pumpkin.to_string()],
+                    threshold: 2,
+                },
+            )
+            .unwrap();
+
+        let execute = |deps: DepsMut, sender: &Addr, action: CommitteeAction| {
+            COMMITTEE.execute_update_ownership(deps, &env, &message_info(sender, &[]), action)
+        };
+
+        // jake proposes an ownership action before being removed
+        execute(
+            deps.as_mut(),
+            &jake,
+            CommitteeAction::Propose {
+                action: ProposalAction::UpdateOwnership(Action::RenounceOwnership),
+                expiry: None,
+            },
+        )
+        .unwrap();
+
+        // the new committee's threshold must be reachable
+        {
+            let err = execute(
+                deps.as_mut(),
+                &larry,
+                CommitteeAction::Propose {
+                    action: ProposalAction::UpdateCommittee(Committee {
+                        members: vec![larry.to_string(), pumpkin.to_string()],
+                        threshold: 3,
+                    }),
+                    expiry: None,
+                },
+            )
+            .unwrap_err();
+            assert_eq!(err, OwnershipError::InvalidThreshold);
+        }
+
+        // the committee is updated once the proposal reaches the threshold
+        {
+            let new_committee = Committee {
+                members: vec![larry.to_string(), pumpkin.to_string()],
+                threshold: 2,
+            };
+            execute(
+                deps.as_mut(),
+                &larry,
+                CommitteeAction::Propose {
+                    action: ProposalAction::UpdateCommittee(new_committee),
+                    expiry: None,
+                },
+            )
+            .unwrap();
+            execute(
+                deps.as_mut(),
+                &pumpkin,
+                CommitteeAction::Approve {
+                    proposal_id: 2,
+                },
+            )
+            .unwrap();
+
+            assert!(COMMITTEE.is_member(deps.as_ref().storage, &pumpkin).unwrap());
+            assert!(!COMMITTEE.is_member(deps.as_ref().storage, &jake).unwrap());
+        }
+
+        // the removed member's approval no longer counts
+        {
+            let res = execute(
+                deps.as_mut(),
+                &larry,
+                CommitteeAction::Approve {
+                    proposal_id: 1,
+                },
+            )
+            .unwrap();
+            assert_eq!(res.attributes, [("proposal_id", "1"), ("executed", "false")]);
+
+            let err = execute(
+                deps.as_mut(),
+                &jake,
+                CommitteeAction::Approve {
+                    proposal_id: 1,
+                },
+            )
+            .unwrap_err();
+            assert_eq!(err, OwnershipError::NotCommitteeMember);
+        }
+
+        // the committee can be replaced outside of proposals, e.g. in `sudo`
+        {
+            let committee = COMMITTEE
+                .update_committee(
+                    &mut deps.storage,
+                    &deps.api,
+                    Committee {
+                        members: vec![jake.to_string()],
+                        threshold: 1,
+                    },
+                )
+                .unwrap();
+            assert_eq!(committee.members, vec![jake]);
+        }
+    }
+}
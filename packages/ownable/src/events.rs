@@ -0,0 +1,66 @@
+use cosmwasm_std::{Addr, Coin, Event};
+
+use crate::{none_or, ChangeKind, Ownership};
+
+impl ChangeKind {
+    /// The type of the wasm event emitted for this kind of change.
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            ChangeKind::TransferProposed => "ownership_transfer_proposed",
+            ChangeKind::TransferAccepted => "ownership_accepted",
+            ChangeKind::OwnershipRenounced => "ownership_renounced",
+            ChangeKind::TransferCanceled => "ownership_transfer_canceled",
+            ChangeKind::TransferRejected => "ownership_transfer_rejected",
+            ChangeKind::RenounceProposed => "ownership_renounce_proposed",
+            ChangeKind::RenounceCanceled => "ownership_renounce_canceled",
+            ChangeKind::OwnerSetBySudo => "ownership_set_by_sudo",
+            ChangeKind::TransferClearedBySudo => "ownership_transfer_cleared_by_sudo",
+            ChangeKind::LeaseSet => "ownership_lease_set",
+            ChangeKind::LeaseExtended => "ownership_lease_extended",
+            ChangeKind::LeaseEnded => "ownership_lease_ended",
+            ChangeKind::Heartbeat => "ownership_heartbeat",
+            ChangeKind::RecoveryAddressSet => "ownership_recovery_address_set",
+            ChangeKind::OwnershipRecovered => "ownership_recovered",
+        }
+    }
+}
+
+/// Build a wasm event describing a change made to the ownership.
+///
+/// The event's type is given by [`ChangeKind::event_type`], so it doesn't
+/// collide with the contract's own attributes. Values are serialized the same
+/// way as in [`Ownership::into_attributes`].
+///
+/// Attribute keys used:
+///  - actor
+///  - previous_owner
+///  - new_owner
+///  - pending_owner
+///  - pending_expiry
+///  - pending_price, only if the pending transfer is priced
+///  - lease_expiry and fallback_owner, only if the new ownership is leased
+pub fn ownership_event(
+    kind: ChangeKind,
+    actor: &Addr,
+    previous_ownership: &Ownership<Addr>,
+    new_ownership: &Ownership<Addr>,
+) -> Event {
+    let mut event = Event::new(kind.event_type())
+        .add_attribute("actor", actor)
+        .add_attribute("previous_owner", none_or(previous_ownership.owner.as_ref()))
+        .add_attribute("new_owner", none_or(new_ownership.owner.as_ref()))
+        .add_attribute("pending_owner", none_or(new_ownership.pending_owner.as_ref()))
+        .add_attribute("pending_expiry", none_or(new_ownership.pending_expiry.as_ref()));
+
+    if let Some(price) = &new_ownership.pending_price {
+        let price = price.iter().map(Coin::to_string).collect::<Vec<_>>().join(",");
+        event = event.add_attribute("pending_price", price);
+    }
+
+    match &new_ownership.lease_expiry {
+        Some(lease_expiry) => event
+            .add_attribute("lease_expiry", lease_expiry.to_string())
+            .add_attribute("fallback_owner", none_or(new_ownership.fallback_owner.as_ref())),
+        None => event,
+    }
+}
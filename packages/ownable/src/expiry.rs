@@ -0,0 +1,204 @@
+use std::cmp::Ordering;
+
+use cosmwasm_std::BlockInfo;
+use cw_utils::{Duration, Expiration};
+
+use crate::OwnershipError;
+
+/// Rules that the expiry of an ownership transfer must follow.
+///
+/// The default policy imposes no rules, i.e. any expiry is accepted, including
+/// one that has already passed.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ExpiryPolicy {
+    /// Whether to reject expiries that have already passed.
+    pub strict: bool,
+
+    /// The shortest time the pending owner may be given to accept a transfer.
+    pub min_window: Option<Duration>,
+
+    /// The longest time the pending owner may be given to accept a transfer.
+    /// If set, transfers without an expiry are rejected.
+    pub max_window: Option<Duration>,
+
+    /// The expiry of transfers that don't specify one, relative to the block
+    /// at which the transfer is proposed.
+    pub default_expiry: Option<Duration>,
+}
+
+impl ExpiryPolicy {
+    pub const fn new() -> Self {
+        Self {
+            strict: false,
+            min_window: None,
+            max_window: None,
+            default_expiry: None,
+        }
+    }
+
+    /// Reject expiries that have already passed.
+    pub const fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+
+    /// Require transfers to be acceptable for at least the given duration.
+    pub const fn with_min_window(mut self, window: Duration) -> Self {
+        self.min_window = Some(window);
+        self
+    }
+
+    /// Require transfers to expire within the given duration.
+    pub const fn with_max_window(mut self, window: Duration) -> Self {
+        self.max_window = Some(window);
+        self
+    }
+
+    /// Expire transfers that don't specify an expiry after the given duration.
+    pub const fn with_default_expiry(mut self, expiry: Duration) -> Self {
+        self.default_expiry = Some(expiry);
+        self
+    }
+
+    /// Resolve the absolute expiry of a transfer proposed at the given block,
+    /// from either an absolute or a relative expiry, and check that it follows
+    /// the policy.
+    pub(crate) fn resolve(
+        &self,
+        block: &BlockInfo,
+        expiry: Option<Expiration>,
+        expires_in: Option<Duration>,
+    ) -> Result<Option<Expiration>, OwnershipError> {
+        let expiry = match (expiry, expires_in) {
+            (Some(_), Some(_)) => return Err(OwnershipError::ConflictingExpiry),
+            (Some(expiry), None) => Some(expiry),
+            (None, Some(expires_in)) => Some(expires_in.after(block)),
+            (None, None) => self.default_expiry.map(|expiry| expiry.after(block)),
+        };
+
+        if let Some(expiry) = expiry {
+            if self.strict && expiry.is_expired(block) {
+                return Err(OwnershipError::ExpiryInPast(expiry));
+            }
+        }
+
+        // a transfer without an expiry never expires. expiries measured in
+        // different units than the window can't be compared, so they're rejected
+        let effective = expiry.unwrap_or(Expiration::Never {});
+
+        if let Some(min_window) = self.min_window {
+            let earliest = min_window.after(block);
+            if !matches!(
+                effective.partial_cmp(&earliest),
+                Some(Ordering::Greater | Ordering::Equal)
+            ) {
+                return Err(OwnershipError::ExpiryTooSoon(earliest));
+            }
+        }
+
+        if let Some(max_window) = self.max_window {
+            let latest = max_window.after(block);
+            if !matches!(effective.partial_cmp(&latest), Some(Ordering::Less | Ordering::Equal)) {
+                return Err(OwnershipError::ExpiryTooLate(latest));
+            }
+        }
+
+        Ok(expiry)
+    }
+}
+
+//------------------------------------------------------------------------------
+// Tests
+//------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Timestamp;
+
+    use super::*;
+
+    fn mock_block() -> BlockInfo {
+        BlockInfo {
+            height: 12345,
+            time: Timestamp::from_seconds(10000),
+            chain_id: "".into(),
+        }
+    }
+
+    #[test]
+    fn resolving_relative_expiries() {
+        let block = mock_block();
+        let policy = ExpiryPolicy::new();
+
+        assert_eq!(
+            policy.resolve(&block, None, Some(Duration::Height(100))),
+            Ok(Some(Expiration::AtHeight(12445))),
+        );
+        assert_eq!(
+            policy.resolve(&block, None, Some(Duration::Time(60))),
+            Ok(Some(Expiration::AtTime(Timestamp::from_seconds(10060)))),
+        );
+        assert_eq!(
+            policy.resolve(&block, Some(Expiration::AtHeight(1)), Some(Duration::Height(100))),
+            Err(OwnershipError::ConflictingExpiry),
+        );
+
+        // the default policy accepts anything, including passed expiries
+        assert_eq!(policy.resolve(&block, None, None), Ok(None));
+        assert_eq!(
+            policy.resolve(&block, Some(Expiration::AtHeight(1)), None),
+            Ok(Some(Expiration::AtHeight(1))),
+        );
+
+        // the default expiry applies only when none is given
+        let policy = policy.with_default_expiry(Duration::Height(50));
+        assert_eq!(policy.resolve(&block, None, None), Ok(Some(Expiration::AtHeight(12395))));
+        assert_eq!(
+            policy.resolve(&block, Some(Expiration::Never {}), None),
+            Ok(Some(Expiration::Never {})),
+        );
+    }
+
+    #[test]
+    fn enforcing_expiry_policy() {
+        let block = mock_block();
+
+        let policy = ExpiryPolicy::new().strict();
+        assert_eq!(
+            policy.resolve(&block, Some(Expiration::AtHeight(12345)), None),
+            Err(OwnershipError::ExpiryInPast(Expiration::AtHeight(12345))),
+        );
+        assert_eq!(
+            policy.resolve(&block, Some(Expiration::AtHeight(12346)), None),
+            Ok(Some(Expiration::AtHeight(12346))),
+        );
+
+        let policy = ExpiryPolicy::new()
+            .with_min_window(Duration::Height(100))
+            .with_max_window(Duration::Height(1000));
+        assert_eq!(
+            policy.resolve(&block, None, Some(Duration::Height(99))),
+            Err(OwnershipError::ExpiryTooSoon(Expiration::AtHeight(12445))),
+        );
+        assert_eq!(
+            policy.resolve(&block, None, Some(Duration::Height(1001))),
+            Err(OwnershipError::ExpiryTooLate(Expiration::AtHeight(13345))),
+        );
+        assert_eq!(
+            policy.resolve(&block, None, Some(Duration::Height(500))),
+            Ok(Some(Expiration::AtHeight(12845))),
+        );
+
+        // transfers without an expiry exceed the maximum window
+        assert_eq!(
+            policy.resolve(&block, None, None),
+            Err(OwnershipError::ExpiryTooLate(Expiration::AtHeight(13345))),
+        );
+
+        // expiries in other units can't be compared against the windows
+        assert_eq!(
+            policy.resolve(&block, None, Some(Duration::Time(500))),
+            Err(OwnershipError::ExpiryTooSoon(Expiration::AtHeight(12445))),
+        );
+    }
+}
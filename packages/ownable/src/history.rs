@@ -0,0 +1,128 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, BlockInfo, Order, StdError, StdResult, Storage, Timestamp};
+use cw_storage_plus::{Bound, Map};
+
+use crate::{Action, Ownership};
+
+/// The kind of change made to the contract's ownership
+#[cw_serde]
+#[derive(Copy)]
+pub enum ChangeKind {
+    TransferProposed,
+    TransferAccepted,
+    OwnershipRenounced,
+    TransferCanceled,
+    TransferRejected,
+    RenounceProposed,
+    RenounceCanceled,
+    OwnerSetBySudo,
+    TransferClearedBySudo,
+    LeaseSet,
+    LeaseExtended,
+    LeaseEnded,
+    Heartbeat,
+    RecoveryAddressSet,
+    OwnershipRecovered,
+}
+
+impl From<&Action> for ChangeKind {
+    fn from(action: &Action) -> Self {
+        match action {
+            Action::TransferOwnership {
+                ..
+            } => ChangeKind::TransferProposed,
+            Action::AcceptOwnership => ChangeKind::TransferAccepted,
+            Action::AcceptOwnershipWithSignature {
+                ..
+            } => ChangeKind::TransferAccepted,
+            Action::RenounceOwnership => ChangeKind::OwnershipRenounced,
+            Action::CancelTransfer => ChangeKind::TransferCanceled,
+            Action::RejectTransfer => ChangeKind::TransferRejected,
+            Action::ProposeRenounce {
+                ..
+            } => ChangeKind::RenounceProposed,
+            Action::ConfirmRenounce => ChangeKind::OwnershipRenounced,
+            Action::CancelRenounce => ChangeKind::RenounceCanceled,
+            Action::SetLease {
+                ..
+            } => ChangeKind::LeaseSet,
+            Action::ExtendLease {
+                ..
+            } => ChangeKind::LeaseExtended,
+            Action::EndLease => ChangeKind::LeaseEnded,
+            Action::Heartbeat => ChangeKind::Heartbeat,
+            Action::SetRecoveryAddress {
+                ..
+            } => ChangeKind::RecoveryAddressSet,
+            Action::ClaimRecovery => ChangeKind::OwnershipRecovered,
+        }
+    }
+}
+
+/// A record of a change made to the contract's ownership
+#[cw_serde]
+pub struct OwnershipChange {
+    /// Sequential identifier of the change, starting from 1
+    pub id: u64,
+
+    /// The block height at which the change was made
+    pub height: u64,
+
+    /// The block time at which the change was made
+    pub time: Timestamp,
+
+    /// The account that made the change
+    pub actor: Addr,
+
+    /// The kind of change that was made
+    pub kind: ChangeKind,
+
+    /// The ownership before the change
+    pub previous_ownership: Ownership<Addr>,
+
+    /// The ownership after the change
+    pub new_ownership: Ownership<Addr>,
+}
+
+/// Append a change to the history, assigning it the next sequential id.
+pub(crate) fn record_change(
+    history: &Map<u64, OwnershipChange>,
+    store: &mut dyn Storage,
+    block: &BlockInfo,
+    actor: &Addr,
+    kind: ChangeKind,
+    previous_ownership: Ownership<Addr>,
+    new_ownership: Ownership<Addr>,
+) -> StdResult<()> {
+    let last_id = history.keys(store, None, None, Order::Descending).next().transpose()?;
+    let id = last_id.unwrap_or(0) + 1;
+
+    history.save(
+        store,
+        id,
+        &OwnershipChange {
+            id,
+            height: block.height,
+            time: block.time,
+            actor: actor.clone(),
+            kind,
+            previous_ownership,
+            new_ownership,
+        },
+    )
+}
+
+/// Return the changes recorded in the history, in the order they were made.
+pub(crate) fn query_history(
+    history: Option<&Map<u64, OwnershipChange>>,
+    store: &dyn Storage,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<OwnershipChange>> {
+    let Some(history) = history else {
+        return Err(StdError::generic_err("[cw-ownable]: ownership history is not recorded"));
+    };
+
+    let start = start_after.map(Bound::exclusive);
+    cw_paginate::paginate_map(history, store, start, limit, |_, change| Ok(change))
+}
@@ -1,15 +1,55 @@
 #![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
 
-use std::fmt::Display;
+mod admin;
+mod committee;
+mod events;
+mod expiry;
+mod history;
+mod map;
+mod migrate;
+mod pause;
+mod policy;
+mod query;
+mod recovery;
+mod roles;
+mod signature;
+mod slots;
+mod sudo;
+#[cfg(test)]
+mod testing;
+mod transitive;
+
+use std::{cmp::Ordering, fmt::Display};
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Api, Attribute, BlockInfo, DepsMut, StdError, StdResult, Storage};
+use cosmwasm_std::{
+    Addr, Api, Attribute, BankMsg, Binary, BlockInfo, Coin, Coins, Deps, DepsMut, Env, MessageInfo,
+    QuerierWrapper, Response, StdError, StdResult, Storage,
+};
 use cw_address_like::AddressLike;
-use cw_storage_plus::Item;
-
-// re-export the proc macros and the Expiration class
-pub use cw_ownable_derive::{cw_ownable_execute, cw_ownable_query};
-pub use cw_utils::Expiration;
+use cw_storage_plus::{Item, Map};
+
+// re-export the proc macros and the Expiration and Duration classes
+pub use cw_ownable_derive::{
+    cw_ownable_execute, cw_ownable_query, cw_ownable_sudo, cw_pausable_execute, cw_pausable_query,
+    cw_roles_execute, cw_roles_query, only_owner,
+};
+pub use admin::{admin_sync_msg, AdminSyncStatus};
+pub use committee::{Committee, CommitteeAction, CommitteeStore, Proposal, ProposalAction};
+pub use cw_utils::{Duration, Expiration};
+pub use events::ownership_event;
+pub use expiry::ExpiryPolicy;
+pub use history::{ChangeKind, OwnershipChange};
+pub use map::{OwnershipIndexes, OwnershipMap};
+pub use migrate::{detect_legacy_layout, LegacyLayout};
+pub use pause::{PauseAction, PauseError, PauseInfo, PauseStore};
+pub use policy::TransferPolicy;
+pub use query::{OwnershipQuery, PendingOwnership};
+pub use recovery::{Activity, RecoveryStatus};
+pub use roles::{RoleAction, RoleError, RoleStore};
+pub use signature::{AcceptancePayload, PublicKey, ACCEPTANCE_DOMAIN};
+pub use slots::OwnershipSlots;
+pub use sudo::SudoAction;
 
 /// The contract's ownership info
 #[cw_serde]
@@ -26,19 +66,154 @@ pub struct Ownership<T: AddressLike> {
     /// `None` if there isn't a pending ownership transfer, or if a transfer
     /// exists and it doesn't have a deadline.
     pub pending_expiry: Option<Expiration>,
+
+    /// The earliest time at which the pending owner may accept the ownership.
+    /// `None` if there isn't a pending ownership transfer, or if a transfer
+    /// exists and it isn't subject to a delay.
+    pub pending_accept_after: Option<Expiration>,
+
+    /// The public key with which the pending owner may accept the ownership
+    /// by signing off-chain. `None` if there isn't a pending ownership
+    /// transfer, or if a transfer exists and it can only be accepted on-chain.
+    pub pending_pubkey: Option<PublicKey>,
+
+    /// The funds that the pending owner must send to accept the ownership,
+    /// which are forwarded to the owner. `None` if there isn't a pending
+    /// ownership transfer, or if a transfer exists and it's free.
+    pub pending_price: Option<Vec<Coin>>,
+
+    /// The earliest time at which the owner may confirm renouncing the
+    /// ownership. `None` if a renouncement hasn't been proposed.
+    pub pending_renounce: Option<Expiration>,
+
+    /// The time at which the owner's lease ends, after which the fallback
+    /// owner takes over the ownership. `None` if the ownership isn't leased.
+    pub lease_expiry: Option<Expiration>,
+
+    /// The account who takes over the ownership once the lease ends.
+    /// `None` if the ownership isn't leased.
+    pub fallback_owner: Option<T>,
+
+    /// The account who may claim the ownership once the owner has been
+    /// inactive for the ownership's recovery period.
+    /// `None` if a recovery address hasn't been set.
+    pub recovery_address: Option<T>,
+
+    /// The block at which the owner last acted on the ownership.
+    /// `None` if the owner hasn't acted since the ownership was initialized.
+    pub last_activity: Option<Activity>,
 }
 
 pub struct OwnershipStore {
     pub item: Item<Ownership<Addr>>,
+
+    /// The minimum delay between an ownership transfer being proposed and it
+    /// being accepted. `None` if transfers can be accepted immediately.
+    pub transfer_delay: Option<Duration>,
+
+    /// The minimum delay between a renouncement being proposed and it being
+    /// confirmed. If set, the ownership can't be renounced in a single step.
+    pub renounce_delay: Option<Duration>,
+
+    /// The rules that the expiry of ownership transfers must follow.
+    pub expiry_policy: ExpiryPolicy,
+
+    /// The rules restricting who may be proposed as the new owner.
+    pub transfer_policy: TransferPolicy,
+
+    /// Whether ownership changes also update the contract's wasm-level admin.
+    pub sync_admin: bool,
+
+    /// Namespace under which the changes made to the ownership are recorded.
+    /// `None` if changes aren't recorded.
+    pub history_namespace: Option<&'static str>,
+
+    /// Namespace under which the nonces of signed acceptances are recorded.
+    /// `None` if the ownership can't be accepted with a signature.
+    pub signature_nonces_namespace: Option<&'static str>,
+
+    /// How long the owner must be inactive before the recovery address may
+    /// claim the ownership. `None` if the ownership can't be recovered.
+    pub recovery_period: Option<Duration>,
 }
 
 impl OwnershipStore {
     pub const fn new(key: &'static str) -> Self {
         Self {
             item: Item::new(key),
+            transfer_delay: None,
+            renounce_delay: None,
+            expiry_policy: ExpiryPolicy::new(),
+            transfer_policy: TransferPolicy::Any,
+            sync_admin: false,
+            history_namespace: None,
+            signature_nonces_namespace: None,
+            recovery_period: None,
         }
     }
 
+    /// Require every ownership transfer to wait for the given delay before it
+    /// can be accepted, giving users time to react to a malicious transfer.
+    pub const fn with_transfer_delay(mut self, delay: Duration) -> Self {
+        self.transfer_delay = Some(delay);
+        self
+    }
+
+    /// Require the ownership to be renounced in two steps, i.e. proposed and
+    /// then confirmed once the given delay has elapsed, so that a single
+    /// mistaken message can't lock the contract forever.
+    pub const fn with_renounce_delay(mut self, delay: Duration) -> Self {
+        self.renounce_delay = Some(delay);
+        self
+    }
+
+    /// Require the expiry of every ownership transfer to follow the given
+    /// policy, e.g. to reject expiries that have already passed.
+    pub const fn with_expiry_policy(mut self, policy: ExpiryPolicy) -> Self {
+        self.expiry_policy = policy;
+        self
+    }
+
+    /// Restrict who may be proposed as the new owner, e.g. to contracts only,
+    /// so that the ownership can't be transferred to the wrong kind of account.
+    pub const fn with_transfer_policy(mut self, policy: TransferPolicy) -> Self {
+        self.transfer_policy = policy;
+        self
+    }
+
+    /// Keep the contract's wasm-level admin, who can migrate the contract, in
+    /// sync with its owner. When the owner changes, `execute_update_ownership`
+    /// returns the message that updates or clears the admin accordingly.
+    ///
+    /// The contract must be its own admin for these messages to succeed.
+    pub const fn with_admin_sync(mut self) -> Self {
+        self.sync_admin = true;
+        self
+    }
+
+    /// Record every change made to the ownership under the given namespace,
+    /// so that it can be audited later.
+    pub const fn with_history(mut self, namespace: &'static str) -> Self {
+        self.history_namespace = Some(namespace);
+        self
+    }
+
+    /// Allow pending owners to accept the ownership with an off-chain
+    /// signature submitted by anyone, recording the used nonces under the
+    /// given namespace so that signatures can't be replayed.
+    pub const fn with_signed_acceptance(mut self, nonces_namespace: &'static str) -> Self {
+        self.signature_nonces_namespace = Some(nonces_namespace);
+        self
+    }
+
+    /// Allow the owner to set a recovery address, who may claim the ownership
+    /// once the owner has been inactive for the given period, so that a lost
+    /// key doesn't lock the contract forever.
+    pub const fn with_recovery_period(mut self, period: Duration) -> Self {
+        self.recovery_period = Some(period);
+        self
+    }
+
     /// Set the given address as the contract owner.
     ///
     /// This function is only intended to be used only during contract instantiation.
@@ -48,240 +223,1200 @@ impl OwnershipStore {
         api: &dyn Api,
         owner: Option<&str>,
     ) -> StdResult<Ownership<Addr>> {
-        let ownership = Ownership {
-            owner: owner.map(|h| api.addr_validate(h)).transpose()?,
-            pending_owner: None,
-            pending_expiry: None,
-        };
+        let ownership = Ownership::new(owner.map(|h| api.addr_validate(h)).transpose()?);
         self.item.save(storage, &ownership)?;
         Ok(ownership)
     }
 
-    /// Return Ok(true) if the contract has an owner and it's the given address.
-    /// Return Ok(false) if the contract doesn't have an owner, of if it does but
-    /// it's not the given address.
-    /// Return Err if fails to load ownership info from storage.
-    pub fn is_owner(&self, store: &dyn Storage, addr: &Addr) -> StdResult<bool> {
-        let ownership = self.item.load(store)?;
+    /// Set the given ownership, including a pending transfer if there is one,
+    /// after validating its addresses.
+    ///
+    /// The owner's last activity is set to the given block, regardless of the
+    /// one given.
+    ///
+    /// This function is only intended to be used only during contract instantiation.
+    pub fn initialize_ownership(
+        &self,
+        storage: &mut dyn Storage,
+        api: &dyn Api,
+        block: &BlockInfo,
+        ownership: Ownership<String>,
+    ) -> Result<Ownership<Addr>, OwnershipError> {
+        let has_pending_terms = ownership.pending_expiry.is_some()
+            || ownership.pending_accept_after.is_some()
+            || ownership.pending_pubkey.is_some()
+            || ownership.pending_price.is_some();
+        if ownership.pending_owner.is_none() && has_pending_terms {
+            return Err(OwnershipError::PendingOwnerMissing);
+        }
+
+        if ownership.lease_expiry.is_some() != ownership.fallback_owner.is_some() {
+            return Err(OwnershipError::LeaseTermsIncomplete);
+        }
+
+        // the pending transfer must not expire before it can be accepted
+        check_acceptable(
+            ownership.pending_accept_after.as_ref(),
+            ownership.pending_expiry.as_ref(),
+        )?;
+
+        let mut ownership = ownership.validate(api)?;
+
+        // the price, if any, must be valid, as it's paid through
+        // `execute_update_ownership`
+        if let Some(price) = ownership.pending_price {
+            ownership.pending_price = Some(self.rules().validate_price(price)?);
+        }
+
+        if let (Some(pending_owner), Some(pubkey)) =
+            (&ownership.pending_owner, &ownership.pending_pubkey)
+        {
+            if self.signature_nonces_namespace.is_none() {
+                return Err(OwnershipError::SignedAcceptanceDisabled);
+            }
+            pubkey.check_controls(api, pending_owner)?;
+        }
+
+        if ownership.recovery_address.is_some() && self.recovery_period.is_none() {
+            return Err(OwnershipError::RecoveryDisabled);
+        }
 
-        if let Some(owner) = ownership.owner {
-            if *addr == owner {
-                return Ok(true);
+        // a proposed renouncement must be the owner's, and respect the minimum
+        // delay as if it were proposed now
+        if let Some(pending_renounce) = &ownership.pending_renounce {
+            if ownership.owner.is_none() {
+                return Err(OwnershipError::NoOwner);
+            }
+            if let Some(min) = self.renounce_delay {
+                let earliest = min.after(block);
+                if !matches!(
+                    pending_renounce.partial_cmp(&earliest),
+                    Some(Ordering::Greater | Ordering::Equal)
+                ) {
+                    return Err(OwnershipError::RenounceDelayTooShort(min));
+                }
             }
         }
 
-        Ok(false)
+        // a back-dated activity would let the recovery address claim the
+        // ownership early, so the owner's inactivity is counted from now
+        ownership.last_activity = ownership.owner.as_ref().map(|_| Activity::at(block));
+
+        self.item.save(storage, &ownership)?;
+        Ok(ownership)
+    }
+
+    /// Return Ok(true) if the contract has an owner at the given block and it's
+    /// the given address. Once the ownership's lease has ended, the fallback
+    /// owner is the owner.
+    /// Return Ok(false) if the contract doesn't have an owner, of if it does but
+    /// it's not the given address.
+    /// Return Err if fails to load ownership info from storage.
+    pub fn is_owner(&self, store: &dyn Storage, block: &BlockInfo, addr: &Addr) -> StdResult<bool> {
+        let ownership = self.get_ownership(store, block)?;
+        Ok(ownership.owner.as_ref() == Some(addr))
     }
 
-    /// Assert that an account is the contract's current owner.
-    pub fn assert_owner(&self, store: &dyn Storage, sender: &Addr) -> Result<(), OwnershipError> {
-        let ownership = self.item.load(store)?;
-        self.check_owner(&ownership, sender)
+    /// Assert that an account is the contract's owner at the given block. Once
+    /// the ownership's lease has ended, the fallback owner is the owner.
+    pub fn assert_owner(
+        &self,
+        store: &dyn Storage,
+        block: &BlockInfo,
+        sender: &Addr,
+    ) -> Result<(), OwnershipError> {
+        let ownership = self.get_ownership(store, block)?;
+        check_owner(&ownership, sender)
     }
 
-    /// Assert that an account is the contract's current owner.
-    fn check_owner(
+    /// Assert that an account is the contract's owner as it's stored, for
+    /// handlers that don't have access to the current block.
+    ///
+    /// The stored owner is only updated once an action is taken after the lease
+    /// has ended, so until then a former lessee still passes this check. Prefer
+    /// [`OwnershipStore::assert_owner`] for leased ownerships.
+    pub fn assert_stored_owner(
         &self,
-        ownership: &Ownership<Addr>,
+        store: &dyn Storage,
         sender: &Addr,
     ) -> Result<(), OwnershipError> {
-        // the contract must have an owner
-        let Some(current_owner) = &ownership.owner else {
-            return Err(OwnershipError::NoOwner);
+        let ownership = self.item.load(store)?;
+        check_owner(&ownership, sender)
+    }
+
+    /// Update the contract's ownership info based on the given action.
+    /// Return the updated ownership.
+    ///
+    /// If the ownership's history is recorded, the change is appended to it.
+    pub fn update_ownership(
+        &self,
+        deps: DepsMut,
+        block: &BlockInfo,
+        sender: &Addr,
+        action: Action,
+    ) -> Result<Ownership<Addr>, OwnershipError> {
+        let (_, ownership) = self.update(deps, block, sender, None, action)?;
+        Ok(ownership)
+    }
+
+    /// Update the contract's ownership info based on the given action.
+    /// Return a response containing a wasm event that describes the change,
+    /// and, if admin sync is enabled, the message that updates the admin.
+    ///
+    /// The funds sent along are used to pay for a priced transfer, in which
+    /// case the response also forwards them to the previous owner. Because of
+    /// this, priced transfers can only be accepted through this function.
+    ///
+    /// See [`ownership_event`] for the event's format.
+    ///
+    /// For a signed acceptance, the pending owner, rather than the account that
+    /// submitted the signature, is recorded as the actor.
+    pub fn execute_update_ownership(
+        &self,
+        mut deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        action: Action,
+    ) -> Result<Response, OwnershipError> {
+        let kind = ChangeKind::from(&action);
+        let (sender, action) = match action {
+            Action::AcceptOwnershipWithSignature {
+                signature,
+                nonce,
+            } => {
+                let pending_owner =
+                    self.verify_signed_acceptance(deps.branch(), env, &signature, nonce)?;
+                (pending_owner, Action::AcceptOwnership)
+            },
+            action => (info.sender.clone(), action),
+        };
+
+        let (previous_ownership, ownership) =
+            self.update(deps, &env.block, &sender, Some(&info.funds), action)?;
+        let event = ownership_event(kind, &sender, &previous_ownership, &ownership);
+        let admin_msg = self
+            .sync_admin
+            .then(|| admin_sync_msg(&env.contract.address, &previous_ownership, &ownership))
+            .flatten();
+        let payment_msg = match (kind, previous_ownership.owner, previous_ownership.pending_price) {
+            (ChangeKind::TransferAccepted, Some(previous_owner), Some(price)) => {
+                Some(BankMsg::Send {
+                    to_address: previous_owner.into(),
+                    amount: price,
+                })
+            },
+            _ => None,
         };
+        Ok(Response::new().add_event(event).add_messages(admin_msg).add_messages(payment_msg))
+    }
+
+    /// Get the ownership value as it stands at the given block, i.e. with the
+    /// fallback owner as the owner if the lease has ended.
+    pub fn get_ownership(
+        &self,
+        storage: &dyn Storage,
+        block: &BlockInfo,
+    ) -> StdResult<Ownership<Addr>> {
+        Ok(self.item.load(storage)?.resolve_lease(block))
+    }
+
+    /// Return the changes made to the ownership, in the order they were made.
+    /// Return Err if the ownership's history isn't recorded.
+    pub fn query_history(
+        &self,
+        storage: &dyn Storage,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<OwnershipChange>> {
+        history::query_history(self.history().as_ref(), storage, start_after, limit)
+    }
+
+    fn history(&self) -> Option<Map<u64, OwnershipChange>> {
+        self.history_namespace.map(Map::new)
+    }
 
-        // the sender must be the current owner
-        if sender != current_owner {
-            return Err(OwnershipError::NotOwner);
+    /// Apply the action and record it in the history, if enabled.
+    /// Return the ownership before and after the change.
+    ///
+    /// The funds are those sent along to pay for a priced transfer, or `None`
+    /// if they can't be forwarded to the owner, in which case priced transfers
+    /// are rejected.
+    fn update(
+        &self,
+        mut deps: DepsMut,
+        block: &BlockInfo,
+        sender: &Addr,
+        funds: Option<&[Coin]>,
+        action: Action,
+    ) -> Result<(Ownership<Addr>, Ownership<Addr>), OwnershipError> {
+        let kind = ChangeKind::from(&action);
+        let previous_ownership = self.item.load(deps.storage)?;
+        let ownership = self.apply_action(deps.branch(), block, sender, funds, action)?;
+
+        if let Some(history) = self.history() {
+            history::record_change(
+                &history,
+                deps.storage,
+                block,
+                sender,
+                kind,
+                previous_ownership.clone(),
+                ownership.clone(),
+            )?;
         }
 
-        Ok(())
+        Ok((previous_ownership, ownership))
     }
 
-    /// Update the contract's ownership info based on the given action.
-    /// Return the updated ownership.
-    pub fn update_ownership(
+    fn apply_action(
         &self,
         deps: DepsMut,
         block: &BlockInfo,
         sender: &Addr,
+        funds: Option<&[Coin]>,
+        action: Action,
+    ) -> Result<Ownership<Addr>, OwnershipError> {
+        // without a response, the price of a transfer can't be forwarded to the
+        // owner, so priced transfers are rejected
+        let rules = Rules {
+            priced_transfers: funds.is_some(),
+            ..self.rules()
+        };
+        let funds = funds.unwrap_or_default();
+        self.item.update(deps.storage, |ownership| {
+            rules.apply(deps.api, &deps.querier, block, sender, funds, ownership, action)
+        })
+    }
+
+    pub(crate) fn rules(&self) -> Rules {
+        Rules {
+            transfer_delay: self.transfer_delay,
+            renounce_delay: self.renounce_delay,
+            expiry_policy: self.expiry_policy,
+            transfer_policy: self.transfer_policy,
+            recovery_period: self.recovery_period,
+            signed_acceptance: self.signature_nonces_namespace.is_some(),
+            priced_transfers: true,
+        }
+    }
+}
+
+/// The rules that changes to an ownership must follow, shared by
+/// [`OwnershipStore`] and [`OwnershipMap`].
+#[derive(Clone, Copy)]
+pub(crate) struct Rules {
+    pub transfer_delay: Option<Duration>,
+    pub renounce_delay: Option<Duration>,
+    pub expiry_policy: ExpiryPolicy,
+    pub transfer_policy: TransferPolicy,
+    pub recovery_period: Option<Duration>,
+
+    /// Whether pending owners may accept the ownership with a signature, i.e.
+    /// the nonces of signed acceptances are recorded.
+    pub signed_acceptance: bool,
+
+    /// Whether the funds paid for a priced transfer can be forwarded to the
+    /// previous owner, i.e. the action is applied through an entry point that
+    /// returns a response.
+    pub priced_transfers: bool,
+}
+
+impl Rules {
+    /// Apply the action to the ownership, returning the updated ownership.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn apply(
+        &self,
+        api: &dyn Api,
+        querier: &QuerierWrapper,
+        block: &BlockInfo,
+        sender: &Addr,
+        funds: &[Coin],
+        ownership: Ownership<Addr>,
         action: Action,
     ) -> Result<Ownership<Addr>, OwnershipError> {
-        match action {
+        // if the lease has ended, the fallback owner has taken over
+        let ownership = ownership.resolve_lease(block);
+        let acted_as_owner = ownership.owner.as_ref() == Some(sender);
+
+        // funds are only accepted as the payment for a priced transfer, rather
+        // than being kept by the contract
+        let pays_price =
+            matches!(action, Action::AcceptOwnership) && ownership.pending_price.is_some();
+        if !funds.is_empty() && !pays_price {
+            return Err(OwnershipError::UnexpectedFunds);
+        }
+
+        let mut ownership = match action {
             Action::TransferOwnership {
                 new_owner,
                 expiry,
-            } => self.transfer_ownership(deps.api, deps.storage, sender, &new_owner, expiry),
-            Action::AcceptOwnership => self.accept_ownership(deps.storage, block, sender),
-            Action::RenounceOwnership => self.renounce_ownership(deps.storage, sender),
+                expires_in,
+                delay,
+                pubkey,
+                price,
+            } => self.transfer_ownership(
+                api, querier, ownership, block, sender, &new_owner, expiry, expires_in, delay,
+                pubkey, price,
+            ),
+            Action::AcceptOwnership => self.accept_ownership(ownership, block, sender, funds),
+            // the signature is verified by `OwnershipStore::execute_update_ownership`,
+            // which then accepts on behalf of the pending owner
+            Action::AcceptOwnershipWithSignature {
+                ..
+            } => Err(OwnershipError::SignedAcceptanceUnsupported),
+            Action::RenounceOwnership => self.renounce_ownership(ownership, sender),
+            Action::ProposeRenounce {
+                delay,
+            } => self.propose_renounce(ownership, block, sender, delay),
+            Action::ConfirmRenounce => self.confirm_renounce(ownership, block, sender),
+            Action::CancelRenounce => self.cancel_renounce(ownership, sender),
+            Action::CancelTransfer => self.cancel_transfer(ownership, sender),
+            Action::RejectTransfer => self.reject_transfer(ownership, sender),
+            Action::SetLease {
+                expiry,
+                fallback_owner,
+            } => self.set_lease(api, ownership, block, sender, expiry, &fallback_owner),
+            Action::ExtendLease {
+                expiry,
+            } => self.extend_lease(ownership, sender, expiry),
+            Action::EndLease => self.end_lease(ownership, sender),
+            Action::Heartbeat => check_owner(&ownership, sender).map(|_| ownership),
+            Action::SetRecoveryAddress {
+                address,
+            } => self.set_recovery_address(api, ownership, sender, address),
+            Action::ClaimRecovery => self.claim_recovery(ownership, block, sender),
+        }?;
+
+        // any action the owner takes, while remaining the owner, shows that its
+        // key hasn't been lost
+        if acted_as_owner && ownership.owner.as_ref() == Some(sender) {
+            ownership.last_activity = Some(Activity::at(block));
         }
-    }
 
-    /// Get the current ownership value.
-    pub fn get_ownership(&self, storage: &dyn Storage) -> StdResult<Ownership<Addr>> {
-        self.item.load(storage)
+        Ok(ownership)
     }
 
     /// Propose to transfer the contract's ownership to the given address, with an
-    /// optional deadline.
+    /// optional deadline, an optional delay before it can be accepted,
+    /// optionally the key with which it can be accepted by signing off-chain,
+    /// and an optional price the new owner must pay.
+    #[allow(clippy::too_many_arguments)]
     fn transfer_ownership(
         &self,
         api: &dyn Api,
-        storage: &mut dyn Storage,
+        querier: &QuerierWrapper,
+        ownership: Ownership<Addr>,
+        block: &BlockInfo,
         sender: &Addr,
         new_owner: &str,
         expiry: Option<Expiration>,
+        expires_in: Option<Duration>,
+        delay: Option<Duration>,
+        pubkey: Option<PublicKey>,
+        price: Option<Vec<Coin>>,
     ) -> Result<Ownership<Addr>, OwnershipError> {
-        self.item.update(storage, |ownership| {
-            // the contract must have an owner
-            self.check_owner(&ownership, sender)?;
-
-            // NOTE: We don't validate the expiry, i.e. asserting it is later than
-            // the current block time.
-            //
-            // This is because if the owner submits an invalid expiry, it won't have
-            // any negative effect - it's just that the pending owner won't be able
-            // to accept the ownership.
-            //
-            // By not doing the check, we save a little bit of gas.
-            //
-            // To fix the erorr, the owner can simply invoke `transfer_ownership`
-            // again with the correct expiry and overwrite the invalid one.
-            Ok(Ownership {
-                pending_owner: Some(api.addr_validate(new_owner)?),
-                pending_expiry: expiry,
-                ..ownership
-            })
+        // the contract must have an owner
+        check_owner(&ownership, sender)?;
+
+        // the new owner must be allowed by the transfer policy
+        let new_owner = api.addr_validate(new_owner)?;
+        self.transfer_policy.check(querier, &new_owner)?;
+
+        // the key, if any, must be usable, and must control the new owner
+        if let Some(pubkey) = &pubkey {
+            if !self.signed_acceptance {
+                return Err(OwnershipError::SignedAcceptanceDisabled);
+            }
+            pubkey.check_controls(api, &new_owner)?;
+        }
+
+        // the price, if any, must be payable and forwardable to the owner
+        let price = price.map(|price| self.validate_price(price)).transpose()?;
+
+        // the transfer's delay may not be shorter than the store's minimum
+        let delay = match (self.transfer_delay, delay) {
+            (Some(min), Some(delay)) if !is_at_least(&delay, &min) => {
+                return Err(OwnershipError::TransferDelayTooShort(min));
+            },
+            (min, delay) => delay.or(min),
+        };
+
+        // NOTE: Unless the expiry policy is strict, we don't validate the
+        // expiry, i.e. asserting it is later than the current block time.
+        //
+        // This is because if the owner submits an invalid expiry, it won't have
+        // any negative effect - it's just that the pending owner won't be able
+        // to accept the ownership.
+        //
+        // By not doing the check, we save a little bit of gas.
+        //
+        // To fix the erorr, the owner can simply invoke `transfer_ownership`
+        // again with the correct expiry and overwrite the invalid one.
+        let expiry = self.expiry_policy.resolve(block, expiry, expires_in)?;
+
+        // the transfer must not expire before its delay has elapsed
+        let accept_after = delay.map(|delay| delay.after(block));
+        check_acceptable(accept_after.as_ref(), expiry.as_ref())?;
+
+        Ok(Ownership {
+            pending_owner: Some(new_owner),
+            pending_expiry: expiry,
+            pending_accept_after: accept_after,
+            pending_pubkey: pubkey,
+            pending_price: price,
+            ..ownership
         })
     }
 
     /// Accept a pending ownership transfer.
     fn accept_ownership(
         &self,
-        store: &mut dyn Storage,
+        ownership: Ownership<Addr>,
         block: &BlockInfo,
         sender: &Addr,
+        funds: &[Coin],
     ) -> Result<Ownership<Addr>, OwnershipError> {
-        self.item.update(store, |ownership| {
-            // there must be an existing ownership transfer
-            let Some(pending_owner) = &ownership.pending_owner else {
-                return Err(OwnershipError::TransferNotFound);
-            };
+        // there must be an existing ownership transfer
+        let Some(pending_owner) = &ownership.pending_owner else {
+            return Err(OwnershipError::TransferNotFound);
+        };
 
-            // the sender must be the pending owner
-            if sender != pending_owner {
-                return Err(OwnershipError::NotPendingOwner);
-            };
+        // the sender must be the pending owner
+        if sender != pending_owner {
+            return Err(OwnershipError::NotPendingOwner);
+        };
 
-            // if the transfer has a deadline, it must not have been reached
-            if let Some(expiry) = &ownership.pending_expiry {
-                if expiry.is_expired(block) {
-                    return Err(OwnershipError::TransferExpired);
-                }
+        // if the transfer has a deadline, it must not have been reached
+        if let Some(expiry) = &ownership.pending_expiry {
+            if expiry.is_expired(block) {
+                return Err(OwnershipError::TransferExpired);
             }
+        }
 
-            Ok(Ownership {
-                owner: ownership.pending_owner,
-                pending_owner: None,
-                pending_expiry: None,
-            })
+        // if the transfer is subject to a delay, it must have elapsed
+        if let Some(accept_after) = &ownership.pending_accept_after {
+            if !accept_after.is_expired(block) {
+                return Err(OwnershipError::TransferNotYetAcceptable(*accept_after));
+            }
+        }
+
+        // if the transfer is priced, the funds sent must match the price exactly
+        if let Some(price) = &ownership.pending_price {
+            let paid = Coins::try_from(funds.to_vec()).ok();
+            let expected =
+                Coins::try_from(price.clone()).map_err(|_| OwnershipError::InvalidPrice)?;
+            if paid.as_ref() != Some(&expected) {
+                return Err(OwnershipError::PriceMismatch(expected));
+            }
+        }
+
+        // the lease, if any, carries over to the new owner, unlike the previous
+        // owner's recovery address, while the new owner's inactivity is counted
+        // from now
+        Ok(Ownership {
+            owner: ownership.pending_owner,
+            pending_owner: None,
+            pending_expiry: None,
+            pending_accept_after: None,
+            pending_pubkey: None,
+            pending_price: None,
+            pending_renounce: None,
+            recovery_address: None,
+            last_activity: Some(Activity::at(block)),
+            ..ownership
         })
     }
 
     /// Set the contract's ownership as vacant permanently.
     fn renounce_ownership(
         &self,
-        store: &mut dyn Storage,
+        ownership: Ownership<Addr>,
         sender: &Addr,
     ) -> Result<Ownership<Addr>, OwnershipError> {
-        self.item.update(store, |ownership| {
-            self.check_owner(&ownership, sender)?;
+        check_owner(&ownership, sender)?;
 
-            Ok(Ownership {
-                owner: None,
-                pending_owner: None,
-                pending_expiry: None,
-            })
+        // if renouncing requires a delay, it must be done in two steps
+        if self.renounce_delay.is_some() {
+            return Err(OwnershipError::RenounceRequiresConfirmation);
+        }
+
+        // the lease, if any, is kept, so that the fallback owner still takes
+        // over once it expires
+        Ok(Ownership {
+            owner: None,
+            pending_owner: None,
+            pending_expiry: None,
+            pending_accept_after: None,
+            pending_pubkey: None,
+            pending_price: None,
+            pending_renounce: None,
+            recovery_address: None,
+            ..ownership
         })
     }
-}
 
-/// Actions that can be taken to alter the contract's ownership
-#[cw_serde]
-pub enum Action {
-    /// Propose to transfer the contract's ownership to another account,
-    /// optionally with an expiry time.
-    ///
-    /// Can only be called by the contract's current owner.
-    ///
-    /// Any existing pending ownership transfer is overwritten.
-    TransferOwnership {
-        new_owner: String,
-        expiry: Option<Expiration>,
-    },
+    /// Propose to renounce the contract's ownership, with an optional delay
+    /// before the renouncement can be confirmed.
+    fn propose_renounce(
+        &self,
+        ownership: Ownership<Addr>,
+        block: &BlockInfo,
+        sender: &Addr,
+        delay: Option<Duration>,
+    ) -> Result<Ownership<Addr>, OwnershipError> {
+        check_owner(&ownership, sender)?;
 
-    /// Accept the pending ownership transfer.
-    ///
-    /// Can only be called by the pending owner.
-    AcceptOwnership,
+        // the renouncement's delay may not be shorter than the store's minimum
+        let delay = match (self.renounce_delay, delay) {
+            (Some(min), Some(delay)) if !is_at_least(&delay, &min) => {
+                return Err(OwnershipError::RenounceDelayTooShort(min));
+            },
+            (min, delay) => delay.or(min).unwrap_or(Duration::Height(0)),
+        };
 
-    /// Give up the contract's ownership and the possibility of appointing
-    /// a new owner.
-    ///
-    /// Can only be invoked by the contract's current owner.
-    ///
-    /// Any existing pending ownership transfer is canceled.
-    RenounceOwnership,
-}
+        Ok(Ownership {
+            pending_renounce: Some(delay.after(block)),
+            ..ownership
+        })
+    }
 
-/// Errors associated with the contract's ownership
-#[derive(thiserror::Error, Debug, PartialEq)]
-pub enum OwnershipError {
-    #[error("{0}")]
-    Std(#[from] StdError),
+    /// Confirm a proposed renouncement, once its delay has elapsed, setting
+    /// the contract's ownership as vacant permanently.
+    fn confirm_renounce(
+        &self,
+        ownership: Ownership<Addr>,
+        block: &BlockInfo,
+        sender: &Addr,
+    ) -> Result<Ownership<Addr>, OwnershipError> {
+        check_owner(&ownership, sender)?;
 
-    #[error("Contract ownership has been renounced")]
-    NoOwner,
+        // there must be an existing renouncement, whose delay has elapsed
+        let Some(confirm_after) = ownership.pending_renounce else {
+            return Err(OwnershipError::RenounceNotProposed);
+        };
+        if !confirm_after.is_expired(block) {
+            return Err(OwnershipError::RenounceNotYetConfirmable(confirm_after));
+        }
 
-    #[error("Caller is not the contract's current owner")]
-    NotOwner,
+        // the lease, if any, is kept, so that the fallback owner still takes
+        // over once it expires
+        Ok(Ownership {
+            owner: None,
+            pending_owner: None,
+            pending_expiry: None,
+            pending_accept_after: None,
+            pending_pubkey: None,
+            pending_price: None,
+            pending_renounce: None,
+            recovery_address: None,
+            ..ownership
+        })
+    }
 
-    #[error("Caller is not the contract's pending owner")]
-    NotPendingOwner,
+    /// Cancel a renouncement proposed by the current owner.
+    fn cancel_renounce(
+        &self,
+        ownership: Ownership<Addr>,
+        sender: &Addr,
+    ) -> Result<Ownership<Addr>, OwnershipError> {
+        check_owner(&ownership, sender)?;
 
-    #[error("There isn't a pending ownership transfer")]
-    TransferNotFound,
+        if ownership.pending_renounce.is_none() {
+            return Err(OwnershipError::RenounceNotProposed);
+        }
 
-    #[error("A pending ownership transfer exists but it has expired")]
-    TransferExpired,
-}
+        Ok(Ownership {
+            pending_renounce: None,
+            ..ownership
+        })
+    }
 
-/// Storage constant for the contract's ownership
-pub const OWNERSHIP_KEY: &str = "ownership";
-const OWNERSHIP: OwnershipStore = OwnershipStore::new(OWNERSHIP_KEY);
+    /// Cancel a pending ownership transfer proposed by the current owner.
+    fn cancel_transfer(
+        &self,
+        ownership: Ownership<Addr>,
+        sender: &Addr,
+    ) -> Result<Ownership<Addr>, OwnershipError> {
+        check_owner(&ownership, sender)?;
 
-/// Set the given address as the contract owner.
-///
-/// This function is only intended to be used only during contract instantiation.
-pub fn initialize_owner(
-    storage: &mut dyn Storage,
-    api: &dyn Api,
-    owner: Option<&str>,
-) -> StdResult<Ownership<Addr>> {
+        // there must be an existing ownership transfer
+        if ownership.pending_owner.is_none() {
+            return Err(OwnershipError::TransferNotFound);
+        }
+
+        Ok(Ownership {
+            pending_owner: None,
+            pending_expiry: None,
+            pending_accept_after: None,
+            pending_pubkey: None,
+            pending_price: None,
+            ..ownership
+        })
+    }
+
+    /// Reject a pending ownership transfer on behalf of the pending owner.
+    fn reject_transfer(
+        &self,
+        ownership: Ownership<Addr>,
+        sender: &Addr,
+    ) -> Result<Ownership<Addr>, OwnershipError> {
+        // there must be an existing ownership transfer
+        let Some(pending_owner) = &ownership.pending_owner else {
+            return Err(OwnershipError::TransferNotFound);
+        };
+
+        // the sender must be the pending owner
+        if sender != pending_owner {
+            return Err(OwnershipError::NotPendingOwner);
+        }
+
+        Ok(Ownership {
+            pending_owner: None,
+            pending_expiry: None,
+            pending_accept_after: None,
+            pending_pubkey: None,
+            pending_price: None,
+            ..ownership
+        })
+    }
+
+    /// Lease the contract's ownership until the given expiry, after which the
+    /// fallback owner takes over.
+    fn set_lease(
+        &self,
+        api: &dyn Api,
+        ownership: Ownership<Addr>,
+        block: &BlockInfo,
+        sender: &Addr,
+        expiry: Expiration,
+        fallback_owner: &str,
+    ) -> Result<Ownership<Addr>, OwnershipError> {
+        check_owner(&ownership, sender)?;
+
+        // the ownership can only be under one lease at a time
+        if ownership.lease_expiry.is_some() {
+            return Err(OwnershipError::LeaseAlreadyExists);
+        }
+
+        if expiry.is_expired(block) {
+            return Err(OwnershipError::ExpiryInPast(expiry));
+        }
+
+        Ok(Ownership {
+            lease_expiry: Some(expiry),
+            fallback_owner: Some(api.addr_validate(fallback_owner)?),
+            ..ownership
+        })
+    }
+
+    /// Extend the lease on the contract's ownership to a later expiry.
+    fn extend_lease(
+        &self,
+        ownership: Ownership<Addr>,
+        sender: &Addr,
+        expiry: Expiration,
+    ) -> Result<Ownership<Addr>, OwnershipError> {
+        // there must be an existing lease
+        let Some(current_expiry) = ownership.lease_expiry else {
+            return Err(OwnershipError::LeaseNotFound);
+        };
+
+        check_owner_or_fallback(&ownership, sender)?;
+
+        // expiries measured in different units can't be compared, so they
+        // never extend the lease
+        if expiry.partial_cmp(&current_expiry) != Some(Ordering::Greater) {
+            return Err(OwnershipError::LeaseNotExtended(current_expiry));
+        }
+
+        Ok(Ownership {
+            lease_expiry: Some(expiry),
+            ..ownership
+        })
+    }
+
+    /// End the lease on the contract's ownership early, handing it over to the
+    /// fallback owner.
+    fn end_lease(
+        &self,
+        ownership: Ownership<Addr>,
+        sender: &Addr,
+    ) -> Result<Ownership<Addr>, OwnershipError> {
+        // there must be an existing lease
+        if ownership.lease_expiry.is_none() {
+            return Err(OwnershipError::LeaseNotFound);
+        }
+
+        check_owner_or_fallback(&ownership, sender)?;
+
+        Ok(ownership.revert_to_fallback())
+    }
+
+    /// Normalize the price of a transfer, i.e. sort it by denom, requiring it
+    /// to be non-empty, without duplicate denoms and without zero amounts.
+    fn validate_price(&self, price: Vec<Coin>) -> Result<Vec<Coin>, OwnershipError> {
+        if !self.priced_transfers {
+            return Err(OwnershipError::PricedTransferUnsupported);
+        }
+
+        if price.is_empty() || price.iter().any(|coin| coin.amount.is_zero()) {
+            return Err(OwnershipError::InvalidPrice);
+        }
+
+        Coins::try_from(price).map(Coins::into_vec).map_err(|_| OwnershipError::InvalidPrice)
+    }
+
+    /// Set or remove the account who may claim the contract's ownership once
+    /// the owner has been inactive for the recovery period.
+    fn set_recovery_address(
+        &self,
+        api: &dyn Api,
+        ownership: Ownership<Addr>,
+        sender: &Addr,
+        address: Option<String>,
+    ) -> Result<Ownership<Addr>, OwnershipError> {
+        check_owner(&ownership, sender)?;
+
+        if self.recovery_period.is_none() {
+            return Err(OwnershipError::RecoveryDisabled);
+        }
+
+        Ok(Ownership {
+            recovery_address: address.map(|addr| api.addr_validate(&addr)).transpose()?,
+            ..ownership
+        })
+    }
+
+    /// Claim the contract's ownership on behalf of the recovery address, once
+    /// the owner has been inactive for the recovery period.
+    fn claim_recovery(
+        &self,
+        ownership: Ownership<Addr>,
+        block: &BlockInfo,
+        sender: &Addr,
+    ) -> Result<Ownership<Addr>, OwnershipError> {
+        let Some(period) = self.recovery_period else {
+            return Err(OwnershipError::RecoveryDisabled);
+        };
+
+        // the sender must be the recovery address
+        if ownership.recovery_address.as_ref() != Some(sender) {
+            return Err(OwnershipError::NotRecoveryAddress);
+        }
+
+        // the owner must have been inactive for the recovery period. If the
+        // owner hasn't acted since the ownership was initialized, its
+        // inactivity can't be measured, so the ownership can't be recovered.
+        let Some(recoverable_at) = ownership.last_activity.map(|activity| activity.plus(period))
+        else {
+            return Err(OwnershipError::RecoveryNotYetClaimable(Expiration::Never {}));
+        };
+        if !recoverable_at.is_expired(block) {
+            return Err(OwnershipError::RecoveryNotYetClaimable(recoverable_at));
+        }
+
+        Ok(Ownership {
+            owner: ownership.recovery_address,
+            pending_owner: None,
+            pending_expiry: None,
+            pending_accept_after: None,
+            pending_pubkey: None,
+            pending_price: None,
+            pending_renounce: None,
+            recovery_address: None,
+            last_activity: Some(Activity::at(block)),
+            ..ownership
+        })
+    }
+}
+/// Actions that can be taken to alter the contract's ownership
+#[cw_serde]
+pub enum Action {
+    /// Propose to transfer the contract's ownership to another account,
+    /// optionally with an expiry time, and optionally with a delay before
+    /// which the transfer can't be accepted.
+    ///
+    /// The expiry may be given either as an absolute `expiry`, or relative to
+    /// the current block as `expires_in`, but not both. If neither is given,
+    /// the default expiry configured for the ownership, if any, is used.
+    ///
+    /// The delay may not be shorter than the minimum configured for the
+    /// ownership; if not provided, that minimum is used.
+    ///
+    /// If the new owner's public key is given, the new owner may also accept
+    /// the ownership by signing off-chain, see `AcceptOwnershipWithSignature`.
+    ///
+    /// If a price is given, the new owner must send exactly those funds when
+    /// accepting the ownership, which are then forwarded to the current owner.
+    ///
+    /// Can only be called by the contract's current owner.
+    ///
+    /// Any existing pending ownership transfer is overwritten.
+    TransferOwnership {
+        new_owner: String,
+        expiry: Option<Expiration>,
+        expires_in: Option<Duration>,
+        delay: Option<Duration>,
+        pubkey: Option<PublicKey>,
+        price: Option<Vec<Coin>>,
+    },
+
+    /// Accept the pending ownership transfer.
+    ///
+    /// If the transfer is priced, the funds sent must match the price exactly.
+    ///
+    /// Can only be called by the pending owner.
+    AcceptOwnership,
+
+    /// Accept the pending ownership transfer with the pending owner's
+    /// signature of an [`AcceptancePayload`], made with the public key given
+    /// when the transfer was proposed.
+    ///
+    /// Can be called by anyone, e.g. a relayer paying the gas on behalf of
+    /// the pending owner. Each nonce can only be used once by a pending owner.
+    ///
+    /// Can only be handled by `execute_update_ownership`, and only if the
+    /// ownership allows signed acceptances.
+    AcceptOwnershipWithSignature {
+        signature: Binary,
+        nonce: u64,
+    },
+
+    /// Give up the contract's ownership and the possibility of appointing
+    /// a new owner.
+    ///
+    /// Can only be invoked by the contract's current owner, and only if the
+    /// ownership doesn't require renouncing in two steps.
+    ///
+    /// Any existing pending ownership transfer is canceled.
+    RenounceOwnership,
+
+    /// Propose to give up the contract's ownership, optionally with a delay
+    /// before which the renouncement can't be confirmed.
+    ///
+    /// The delay may not be shorter than the minimum configured for the
+    /// ownership; if not provided, that minimum is used.
+    ///
+    /// Can only be invoked by the contract's current owner.
+    ///
+    /// Any existing proposed renouncement is overwritten.
+    ProposeRenounce {
+        delay: Option<Duration>,
+    },
+
+    /// Confirm the proposed renouncement once its delay has elapsed, giving
+    /// up the contract's ownership permanently.
+    ///
+    /// Can only be invoked by the contract's current owner.
+    ///
+    /// Any existing pending ownership transfer is canceled.
+    ConfirmRenounce,
+
+    /// Cancel the proposed renouncement.
+    ///
+    /// Can only be invoked by the contract's current owner.
+    CancelRenounce,
+
+    /// Cancel the pending ownership transfer.
+    ///
+    /// Can only be invoked by the contract's current owner.
+    CancelTransfer,
+
+    /// Reject the pending ownership transfer.
+    ///
+    /// Can only be invoked by the pending owner.
+    RejectTransfer,
+
+    /// Lease the ownership until the given expiry, after which the fallback
+    /// owner takes over the ownership, without a transaction being needed.
+    ///
+    /// The lease carries over to anyone the ownership is transferred to in the
+    /// meantime.
+    ///
+    /// Can only be invoked by the contract's current owner, and only if the
+    /// ownership isn't already leased.
+    SetLease {
+        expiry: Expiration,
+        fallback_owner: String,
+    },
+
+    /// Extend the lease to a later expiry.
+    ///
+    /// Can be invoked by either the contract's current owner or the fallback
+    /// owner.
+    ExtendLease {
+        expiry: Expiration,
+    },
+
+    /// End the lease early, handing the ownership over to the fallback owner.
+    ///
+    /// Can be invoked by either the contract's current owner or the fallback
+    /// owner.
+    ///
+    /// Any existing pending ownership transfer or proposed renouncement is
+    /// canceled.
+    EndLease,
+
+    /// Record that the owner is still active, without changing the ownership,
+    /// which delays the recovery address from claiming the ownership.
+    ///
+    /// Any other action taken by the owner also records its activity.
+    ///
+    /// Can only be invoked by the contract's current owner.
+    Heartbeat,
+
+    /// Set the account who may claim the ownership once the owner has been
+    /// inactive for the ownership's recovery period, or remove it if `None`.
+    ///
+    /// Can only be invoked by the contract's current owner, and only if the
+    /// ownership has a recovery period.
+    SetRecoveryAddress {
+        address: Option<String>,
+    },
+
+    /// Claim the ownership once the owner has been inactive for the
+    /// ownership's recovery period.
+    ///
+    /// Can only be invoked by the recovery address.
+    ///
+    /// Any existing pending ownership transfer or proposed renouncement is
+    /// canceled.
+    ClaimRecovery,
+}
+
+/// Errors associated with the contract's ownership
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum OwnershipError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Contract ownership has been renounced")]
+    NoOwner,
+
+    #[error("Caller is not the contract's current owner")]
+    NotOwner,
+
+    #[error("Caller is not the contract's pending owner")]
+    NotPendingOwner,
+
+    #[error("There isn't a pending ownership transfer")]
+    TransferNotFound,
+
+    #[error("A pending ownership transfer exists but it has expired")]
+    TransferExpired,
+
+    #[error("The pending ownership transfer can't be accepted until {0}")]
+    TransferNotYetAcceptable(Expiration),
+
+    #[error("Ownership transfers must be delayed by at least {0}")]
+    TransferDelayTooShort(Duration),
+
+    #[error("The transfer's expiry must be after it can be accepted, at {0}")]
+    TransferNeverAcceptable(Expiration),
+
+    #[error("Only one of `expiry` and `expires_in` may be provided")]
+    ConflictingExpiry,
+
+    #[error("The transfer's expiry {0} has already passed")]
+    ExpiryInPast(Expiration),
+
+    #[error("Ownership transfers must not expire before {0}")]
+    ExpiryTooSoon(Expiration),
+
+    #[error("Ownership transfers must expire by {0}")]
+    ExpiryTooLate(Expiration),
+
+    #[error("There isn't a proposed renouncement of the ownership")]
+    RenounceNotProposed,
+
+    #[error("Renouncing the ownership must be proposed and then confirmed")]
+    RenounceRequiresConfirmation,
+
+    #[error("The proposed renouncement can't be confirmed until {0}")]
+    RenounceNotYetConfirmable(Expiration),
+
+    #[error("Renouncing the ownership must be delayed by at least {0}")]
+    RenounceDelayTooShort(Duration),
+
+    #[error("Caller is neither the contract's current owner nor its fallback owner")]
+    NotOwnerOrFallback,
+
+    #[error("The ownership isn't leased")]
+    LeaseNotFound,
+
+    #[error("The ownership is already leased")]
+    LeaseAlreadyExists,
+
+    #[error("The lease can only be extended past its current expiry {0}")]
+    LeaseNotExtended(Expiration),
+
+    #[error("A lease requires both an expiry and a fallback owner")]
+    LeaseTermsIncomplete,
+
+    #[error("New owner is not in the transfer policy's allowlist")]
+    NewOwnerNotAllowlisted,
+
+    #[error("New owner must be a contract, as required by the transfer policy")]
+    NewOwnerNotContract,
+
+    #[error("New owner's code id {0} is not allowed by the transfer policy")]
+    NewOwnerCodeIdNotAllowed(u64),
+
+    #[error("The transfer's price must be non-empty, without duplicate denoms or zero amounts")]
+    InvalidPrice,
+
+    #[error("This ownership can't be transferred for a price")]
+    PricedTransferUnsupported,
+
+    #[error("Funds sent must exactly match the transfer's price of {0}")]
+    PriceMismatch(Coins),
+
+    #[error("Funds can only be sent to pay for a priced transfer")]
+    UnexpectedFunds,
+
+    #[error("The ownership can't be accepted with a signature")]
+    SignedAcceptanceDisabled,
+
+    #[error("Accepting the ownership with a signature requires `execute_update_ownership`")]
+    SignedAcceptanceUnsupported,
+
+    #[error("The public key is malformed")]
+    InvalidPubkey,
+
+    #[error("The public key doesn't control the pending owner's account")]
+    PubkeyMismatch,
+
+    #[error("The pending ownership transfer can't be accepted with a signature")]
+    PendingPubkeyNotFound,
+
+    #[error("The signature is invalid")]
+    InvalidSignature,
+
+    #[error("Nonce {0} has already been used")]
+    NonceAlreadyUsed(u64),
+
+    #[error("The ownership can't be recovered")]
+    RecoveryDisabled,
+
+    #[error("Caller is not the ownership's recovery address")]
+    NotRecoveryAddress,
+
+    #[error("The ownership can't be recovered until {0}")]
+    RecoveryNotYetClaimable(Expiration),
+
+    #[error("Caller is not the contract's owner, directly or through the owners {}", transitive::format_path(.0))]
+    NotTransitiveOwner(Vec<Addr>),
+
+    #[error("The contract's owners form a cycle: {}", transitive::format_path(.0))]
+    OwnershipCycle(Vec<Addr>),
+
+    #[error("Caller is not a member of the contract's owning committee")]
+    NotCommitteeMember,
+
+    #[error("Committee threshold must be between 1 and the number of members")]
+    InvalidThreshold,
+
+    #[error("Ownership proposal {0} not found")]
+    ProposalNotFound(u64),
+
+    #[error("Ownership proposal {0} has expired")]
+    ProposalExpired(u64),
+
+    #[error("Ownership proposal {0} has already been executed")]
+    ProposalExecuted(u64),
+
+    #[error("Caller has already approved ownership proposal {0}")]
+    AlreadyApproved(u64),
+
+    #[error("Contract ownership has already been initialized")]
+    OwnershipAlreadyExists,
+
+    #[error("A pending transfer's terms were given without a pending owner")]
+    PendingOwnerMissing,
+
+    #[error("No legacy ownership found to migrate from")]
+    LegacyOwnershipNotFound,
+
+    #[error("Ownership slot `{0}` does not exist")]
+    UnknownOwnershipSlot(String),
+}
+
+/// Storage constant for the contract's ownership
+pub const OWNERSHIP_KEY: &str = "ownership";
+const OWNERSHIP: OwnershipStore = OwnershipStore::new(OWNERSHIP_KEY);
+
+/// Set the given address as the contract owner.
+///
+/// This function is only intended to be used only during contract instantiation.
+pub fn initialize_owner(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    owner: Option<&str>,
+) -> StdResult<Ownership<Addr>> {
     OWNERSHIP.initialize_owner(storage, api, owner)
 }
 
-/// Return Ok(true) if the contract has an owner and it's the given address.
+/// Set the given ownership, including a pending transfer if there is one,
+/// after validating its addresses.
+///
+/// This function is only intended to be used only during contract instantiation.
+pub fn initialize_ownership(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    block: &BlockInfo,
+    ownership: Ownership<String>,
+) -> Result<Ownership<Addr>, OwnershipError> {
+    OWNERSHIP.initialize_ownership(storage, api, block, ownership)
+}
+
+/// Return Ok(true) if the contract has an owner at the given block and it's the
+/// given address. Once the ownership's lease has ended, the fallback owner is
+/// the owner.
 /// Return Ok(false) if the contract doesn't have an owner, of if it does but
 /// it's not the given address.
 /// Return Err if fails to load ownership info from storage.
-pub fn is_owner(store: &dyn Storage, addr: &Addr) -> StdResult<bool> {
-    OWNERSHIP.is_owner(store, addr)
+pub fn is_owner(store: &dyn Storage, block: &BlockInfo, addr: &Addr) -> StdResult<bool> {
+    OWNERSHIP.is_owner(store, block, addr)
+}
+
+/// Assert that an account is the contract's owner at the given block. Once the
+/// ownership's lease has ended, the fallback owner is the owner.
+pub fn assert_owner(
+    store: &dyn Storage,
+    block: &BlockInfo,
+    sender: &Addr,
+) -> Result<(), OwnershipError> {
+    OWNERSHIP.assert_owner(store, block, sender)
 }
 
-/// Assert that an account is the contract's current owner.
-pub fn assert_owner(store: &dyn Storage, sender: &Addr) -> Result<(), OwnershipError> {
-    OWNERSHIP.assert_owner(store, sender)
+/// Assert that an account is the contract's owner as it's stored, for handlers
+/// that don't have access to the current block. Prefer [`assert_owner`] for
+/// leased ownerships.
+pub fn assert_stored_owner(store: &dyn Storage, sender: &Addr) -> Result<(), OwnershipError> {
+    OWNERSHIP.assert_stored_owner(store, sender)
+}
+
+/// Assert that an account is the contract's owner at the given block, and
+/// record the block as the owner's last activity.
+pub fn record_activity(
+    store: &mut dyn Storage,
+    block: &BlockInfo,
+    sender: &Addr,
+) -> Result<(), OwnershipError> {
+    OWNERSHIP.record_activity(store, block, sender)
+}
+
+/// Assert that an account is the contract's owner, either directly or by owning
+/// the owner contract, up to `max_depth` levels of owner contracts.
+/// Return the path followed, from the contract's owner to the account.
+pub fn assert_owner_transitive(
+    querier: QuerierWrapper,
+    store: &dyn Storage,
+    block: &BlockInfo,
+    sender: &Addr,
+    max_depth: u32,
+) -> Result<Vec<Addr>, OwnershipError> {
+    OWNERSHIP.assert_owner_transitive(querier, store, block, sender, max_depth)
 }
 
 /// Update the contract's ownership info based on the given action.
@@ -295,12 +1430,158 @@ pub fn update_ownership(
     OWNERSHIP.update_ownership(deps, block, sender, action)
 }
 
-/// Get the current ownership value.
-pub fn get_ownership(storage: &dyn Storage) -> StdResult<Ownership<Addr>> {
-    OWNERSHIP.get_ownership(storage)
+/// Update the contract's ownership info based on the given action.
+/// Return a response containing a wasm event that describes the change.
+pub fn execute_update_ownership(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    action: Action,
+) -> Result<Response, OwnershipError> {
+    OWNERSHIP.execute_update_ownership(deps, env, info, action)
+}
+
+/// Force a change to the contract's ownership, bypassing the ownership checks.
+///
+/// This function is only intended to be used in the contract's `sudo` entry point.
+pub fn sudo_update_ownership(
+    deps: DepsMut,
+    env: &Env,
+    action: SudoAction,
+) -> Result<Response, OwnershipError> {
+    OWNERSHIP.sudo_update_ownership(deps, env, action)
+}
+
+/// Get the ownership value as it stands at the given block, i.e. with the
+/// fallback owner as the owner if the lease has ended.
+pub fn get_ownership(storage: &dyn Storage, block: &BlockInfo) -> StdResult<Ownership<Addr>> {
+    OWNERSHIP.get_ownership(storage, block)
+}
+
+/// Return the status of the pending ownership transfer at the given block.
+pub fn get_pending_ownership(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+) -> StdResult<PendingOwnership> {
+    OWNERSHIP.get_pending_ownership(storage, block)
+}
+
+/// Return the status of the ownership's recovery at the given block.
+pub fn get_recovery_status(storage: &dyn Storage, block: &BlockInfo) -> StdResult<RecoveryStatus> {
+    OWNERSHIP.get_recovery_status(storage, block)
+}
+
+/// Handle a query about the ownership, serializing the response.
+pub fn query_ownership(deps: Deps, env: &Env, query: OwnershipQuery) -> StdResult<Binary> {
+    OWNERSHIP.query_ownership(deps, env, query)
+}
+
+/// Compare the contract's wasm-level admin against its current owner.
+pub fn query_admin_sync(
+    querier: QuerierWrapper,
+    storage: &dyn Storage,
+    contract: &Addr,
+) -> StdResult<AdminSyncStatus> {
+    OWNERSHIP.query_admin_sync(querier, storage, contract)
+}
+
+/// Migrate the contract's ownership from a legacy storage layout.
+/// Return the detected layout and the migrated ownership.
+///
+/// This function is only intended to be used during contract migration.
+pub fn migrate_legacy_ownership(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    admin_key: &str,
+) -> Result<(LegacyLayout, Ownership<Addr>), OwnershipError> {
+    OWNERSHIP.migrate_legacy_ownership(storage, api, admin_key)
+}
+
+/// Storage constant for the contract's pause info
+pub const PAUSE_KEY: &str = "pause";
+const PAUSE: PauseStore = PauseStore::new(OWNERSHIP, PAUSE_KEY);
+
+/// Return Ok(true) if the contract is paused at the given block.
+/// Return Err if fails to load pause info from storage.
+pub fn is_paused(store: &dyn Storage, block: &BlockInfo) -> StdResult<bool> {
+    PAUSE.is_paused(store, block)
+}
+
+/// Assert that the contract isn't paused at the given block.
+pub fn assert_not_paused(store: &dyn Storage, block: &BlockInfo) -> Result<(), PauseError> {
+    PAUSE.assert_not_paused(store, block)
+}
+
+/// Update the contract's pause info based on the given action.
+/// Return the updated pause info.
+pub fn update_pause(
+    deps: DepsMut,
+    block: &BlockInfo,
+    sender: &Addr,
+    action: PauseAction,
+) -> Result<PauseInfo<Addr>, PauseError> {
+    PAUSE.update_pause(deps, block, sender, action)
+}
+
+/// Get the current pause info.
+pub fn get_pause_info(store: &dyn Storage) -> StdResult<PauseInfo<Addr>> {
+    PAUSE.get_pause_info(store)
+}
+
+/// Storage constant for the contract's role assignments
+pub const ROLES_KEY: &str = "roles";
+const ROLES: RoleStore = RoleStore::new(OWNERSHIP, ROLES_KEY);
+
+/// Return Ok(true) if the given address has been granted the role.
+/// Return Err if fails to load role info from storage.
+pub fn has_role(store: &dyn Storage, role: &str, addr: &Addr) -> StdResult<bool> {
+    ROLES.has_role(store, role, addr)
+}
+
+/// Assert that an account has been granted the role.
+pub fn assert_role(store: &dyn Storage, role: &str, sender: &Addr) -> Result<(), RoleError> {
+    ROLES.assert_role(store, role, sender)
+}
+
+/// Update the contract's role assignments based on the given action.
+pub fn update_roles(
+    deps: DepsMut,
+    block: &BlockInfo,
+    sender: &Addr,
+    action: RoleAction,
+) -> Result<(), RoleError> {
+    ROLES.update_roles(deps, block, sender, action)
+}
+
+/// Return the accounts that have been granted the role, ordered by address.
+pub fn query_role_members(
+    store: &dyn Storage,
+    role: &str,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Addr>> {
+    ROLES.query_role_members(store, role, start_after, limit)
 }
 
 impl<T: AddressLike> Ownership<T> {
+    /// Create an ownership with the given owner, without a pending transfer,
+    /// lease or recovery address.
+    pub fn new(owner: Option<T>) -> Self {
+        Ownership {
+            owner,
+            pending_owner: None,
+            pending_expiry: None,
+            pending_accept_after: None,
+            pending_pubkey: None,
+            pending_price: None,
+            pending_renounce: None,
+            lease_expiry: None,
+            fallback_owner: None,
+            recovery_address: None,
+            last_activity: None,
+        }
+    }
+
     /// Serializes the current ownership state as attributes which may
     /// be used in a message response. Serialization is done according
     /// to the std::fmt::Display implementation for `T` and
@@ -311,10 +1592,12 @@ impl<T: AddressLike> Ownership<T> {
     ///  - owner
     ///  - pending_owner
     ///  - pending_expiry
+    ///  - lease_expiry and fallback_owner, only if the ownership is leased
     ///
     /// Callers should take care not to use these keys elsewhere
     /// in their response as CosmWasm will override reused attribute
-    /// keys.
+    /// keys. Alternatively, use [`ownership_event`] to describe the
+    /// change in its own wasm event.
     ///
     /// # Example
     ///
@@ -325,7 +1608,15 @@ impl<T: AddressLike> Ownership<T> {
     ///     Ownership {
     ///         owner: Some("blue"),
     ///         pending_owner: None,
-    ///         pending_expiry: Some(Expiration::Never {})
+    ///         pending_expiry: Some(Expiration::Never {}),
+    ///         pending_accept_after: None,
+    ///         pending_pubkey: None,
+    ///         pending_price: None,
+    ///         pending_renounce: None,
+    ///         lease_expiry: None,
+    ///         fallback_owner: None,
+    ///         recovery_address: None,
+    ///         last_activity: None,
     ///     }
     ///     .into_attributes(),
     ///     vec![
@@ -336,297 +1627,1445 @@ impl<T: AddressLike> Ownership<T> {
     /// )
     /// ```
     pub fn into_attributes(self) -> Vec<Attribute> {
-        vec![
+        let mut attrs = vec![
             Attribute::new("owner", none_or(self.owner.as_ref())),
             Attribute::new("pending_owner", none_or(self.pending_owner.as_ref())),
             Attribute::new("pending_expiry", none_or(self.pending_expiry.as_ref())),
-        ]
+        ];
+        if let Some(lease_expiry) = &self.lease_expiry {
+            attrs.push(Attribute::new("lease_expiry", lease_expiry.to_string()));
+            attrs.push(Attribute::new("fallback_owner", none_or(self.fallback_owner.as_ref())));
+        }
+        attrs
+    }
+}
+
+impl Ownership<String> {
+    /// Validate the addresses in the ownership, converting it into its checked
+    /// form, e.g. when it's provided in an instantiate message.
+    pub fn validate(&self, api: &dyn Api) -> StdResult<Ownership<Addr>> {
+        let validate = |addr: &Option<String>| -> StdResult<Option<Addr>> {
+            addr.as_ref().map(|addr| api.addr_validate(addr)).transpose()
+        };
+
+        Ok(Ownership {
+            owner: validate(&self.owner)?,
+            pending_owner: validate(&self.pending_owner)?,
+            pending_expiry: self.pending_expiry,
+            pending_accept_after: self.pending_accept_after,
+            pending_pubkey: self.pending_pubkey.clone(),
+            pending_price: self.pending_price.clone(),
+            pending_renounce: self.pending_renounce,
+            lease_expiry: self.lease_expiry,
+            fallback_owner: validate(&self.fallback_owner)?,
+            recovery_address: validate(&self.recovery_address)?,
+            last_activity: self.last_activity,
+        })
+    }
+}
+
+impl Ownership<Addr> {
+    /// Return true if the ownership is leased and the lease has ended at the
+    /// given block.
+    pub fn is_lease_expired(&self, block: &BlockInfo) -> bool {
+        self.lease_expiry.is_some_and(|expiry| expiry.is_expired(block))
+    }
+
+    /// Return the ownership as it stands at the given block. Once the lease has
+    /// ended, the fallback owner is the owner, and any pending transfer or
+    /// renouncement made during the lease is dropped.
+    pub fn resolve_lease(self, block: &BlockInfo) -> Self {
+        if self.is_lease_expired(block) {
+            self.revert_to_fallback()
+        } else {
+            self
+        }
+    }
+
+    fn revert_to_fallback(self) -> Self {
+        Ownership::new(self.fallback_owner)
+    }
+}
+
+impl From<Ownership<Addr>> for Ownership<String> {
+    fn from(ownership: Ownership<Addr>) -> Self {
+        Ownership {
+            owner: ownership.owner.map(String::from),
+            pending_owner: ownership.pending_owner.map(String::from),
+            pending_expiry: ownership.pending_expiry,
+            pending_accept_after: ownership.pending_accept_after,
+            pending_pubkey: ownership.pending_pubkey,
+            pending_price: ownership.pending_price,
+            pending_renounce: ownership.pending_renounce,
+            lease_expiry: ownership.lease_expiry,
+            fallback_owner: ownership.fallback_owner.map(String::from),
+            recovery_address: ownership.recovery_address.map(String::from),
+            last_activity: ownership.last_activity,
+        }
+    }
+}
+
+/// Assert that an account is the ownership's current owner.
+pub(crate) fn check_owner(
+    ownership: &Ownership<Addr>,
+    sender: &Addr,
+) -> Result<(), OwnershipError> {
+    // the contract must have an owner
+    let Some(current_owner) = &ownership.owner else {
+        return Err(OwnershipError::NoOwner);
+    };
+
+    // the sender must be the current owner
+    if sender != current_owner {
+        return Err(OwnershipError::NotOwner);
+    }
+
+    Ok(())
+}
+
+/// Assert that an account is either the ownership's current owner or, if it's
+/// leased, its fallback owner.
+fn check_owner_or_fallback(
+    ownership: &Ownership<Addr>,
+    sender: &Addr,
+) -> Result<(), OwnershipError> {
+    if ownership.owner.as_ref() != Some(sender) && ownership.fallback_owner.as_ref() != Some(sender)
+    {
+        return Err(OwnershipError::NotOwnerOrFallback);
     }
+
+    Ok(())
 }
 
 fn none_or<T: Display>(or: Option<&T>) -> String {
     or.map_or_else(|| "none".to_string(), |or| or.to_string())
 }
 
+/// Return true if the duration is at least as long as the minimum. Durations
+/// measured in different units can't be compared, so they never satisfy this.
+/// Return Err if a transfer expires at or before the end of its delay, so that
+/// it can never be accepted. Expirations that can't be compared, i.e. one in
+/// blocks and the other in time, are left to be checked on acceptance.
+fn check_acceptable(
+    accept_after: Option<&Expiration>,
+    expiry: Option<&Expiration>,
+) -> Result<(), OwnershipError> {
+    if let (Some(accept_after), Some(expiry)) = (accept_after, expiry) {
+        if matches!(expiry.partial_cmp(accept_after), Some(Ordering::Less | Ordering::Equal)) {
+            return Err(OwnershipError::TransferNeverAcceptable(*accept_after));
+        }
+    }
+    Ok(())
+}
+
+fn is_at_least(duration: &Duration, min: &Duration) -> bool {
+    match (duration, min) {
+        (Duration::Height(duration), Duration::Height(min)) => duration >= min,
+        (Duration::Time(duration), Duration::Time(min)) => duration >= min,
+        _ => false,
+    }
+}
+
 //------------------------------------------------------------------------------
 // Tests
 //------------------------------------------------------------------------------
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::{testing::{mock_dependencies, MockApi}, Timestamp};
+    use cosmwasm_std::{
+        testing::{message_info, mock_dependencies, mock_env},
+        Event, Timestamp,
+    };
+
+    use super::*;
+    use crate::testing::{mock_addresses, mock_block_at_height};
+
+    #[test]
+    fn initializing_ownership() {
+        let mut deps = mock_dependencies();
+        let [larry, _, _] = mock_addresses(&deps.api);
+
+        let ownership =
+            OWNERSHIP.initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str())).unwrap();
+
+        // ownership returned is same as ownership stored.
+        assert_eq!(ownership, OWNERSHIP.item.load(deps.as_ref().storage).unwrap());
+
+        assert_eq!(ownership, Ownership::new(Some(larry)));
+    }
+
+    #[test]
+    fn initialize_ownership_no_owner() {
+        let mut deps = mock_dependencies();
+        let ownership = OWNERSHIP.initialize_owner(&mut deps.storage, &deps.api, None).unwrap();
+        assert_eq!(ownership, Ownership::new(None));
+    }
+
+    #[test]
+    fn initializing_complete_ownership() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let [larry, jake, _] = mock_addresses(&deps.api);
+
+        // pending transfer terms require a pending owner
+        {
+            let err = OWNERSHIP
+                .initialize_ownership(
+                    &mut deps.storage,
+                    &deps.api,
+                    &env.block,
+                    Ownership {
+                        owner: Some(larry.to_string()),
+                        pending_owner: None,
+                        pending_expiry: Some(Expiration::AtHeight(42069)),
+                        pending_accept_after: None,
+                        pending_pubkey: None,
+                        pending_price: None,
+                        pending_renounce: None,
+                        lease_expiry: None,
+                        fallback_owner: None,
+                        recovery_address: None,
+                        last_activity: None,
+                    },
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::PendingOwnerMissing);
+
+            let err = OWNERSHIP
+                .initialize_ownership(
+                    &mut deps.storage,
+                    &deps.api,
+                    &env.block,
+                    Ownership {
+                        pending_price: Some(vec![Coin::new(100u128, "uatom")]),
+                        ..Ownership::new(Some(larry.to_string()))
+                    },
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::PendingOwnerMissing);
+        }
+
+        // the pending transfer's price must be valid
+        {
+            let err = OWNERSHIP
+                .initialize_ownership(
+                    &mut deps.storage,
+                    &deps.api,
+                    &env.block,
+                    Ownership {
+                        pending_owner: Some(jake.to_string()),
+                        pending_price: Some(vec![Coin::new(0u128, "uatom")]),
+                        ..Ownership::new(Some(larry.to_string()))
+                    },
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::InvalidPrice);
+        }
+
+        // the pending transfer must not expire before it can be accepted
+        {
+            let err = OWNERSHIP
+                .initialize_ownership(
+                    &mut deps.storage,
+                    &deps.api,
+                    &env.block,
+                    Ownership {
+                        pending_owner: Some(jake.to_string()),
+                        pending_expiry: Some(Expiration::AtHeight(42069)),
+                        pending_accept_after: Some(Expiration::AtHeight(42069)),
+                        ..Ownership::new(Some(larry.to_string()))
+                    },
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::TransferNeverAcceptable(Expiration::AtHeight(42069)));
+        }
+
+        // a recovery address requires a recovery period, and a proposed
+        // renouncement an owner
+        {
+            let err = OWNERSHIP
+                .initialize_ownership(
+                    &mut deps.storage,
+                    &deps.api,
+                    &env.block,
+                    Ownership {
+                        recovery_address: Some(jake.to_string()),
+                        ..Ownership::new(Some(larry.to_string()))
+                    },
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::RecoveryDisabled);
+
+            let err = OWNERSHIP
+                .initialize_ownership(
+                    &mut deps.storage,
+                    &deps.api,
+                    &env.block,
+                    Ownership {
+                        pending_renounce: Some(Expiration::AtHeight(42069)),
+                        ..Ownership::new(None)
+                    },
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::NoOwner);
+        }
+
+        // addresses must be valid
+        {
+            let err = OWNERSHIP
+                .initialize_ownership(
+                    &mut deps.storage,
+                    &deps.api,
+                    &env.block,
+                    Ownership {
+                        owner: Some(larry.to_string()),
+                        pending_owner: Some("jake".into()),
+                        pending_expiry: None,
+                        pending_accept_after: None,
+                        pending_pubkey: None,
+                        pending_price: None,
+                        pending_renounce: None,
+                        lease_expiry: None,
+                        fallback_owner: None,
+                        recovery_address: None,
+                        last_activity: None,
+                    },
+                )
+                .unwrap_err();
+            assert!(matches!(err, OwnershipError::Std(_)));
+            assert!(!OWNERSHIP.item.exists(deps.as_ref().storage));
+        }
+
+        // a pending transfer is properly initialized
+        {
+            let unchecked = Ownership {
+                owner: Some(larry.to_string()),
+                pending_owner: Some(jake.to_string()),
+                pending_expiry: Some(Expiration::AtHeight(42069)),
+                pending_accept_after: None,
+                pending_pubkey: None,
+                pending_price: None,
+                pending_renounce: None,
+                lease_expiry: None,
+                fallback_owner: None,
+                recovery_address: None,
+                last_activity: None,
+            };
+            let ownership = OWNERSHIP
+                .initialize_ownership(&mut deps.storage, &deps.api, &env.block, unchecked.clone())
+                .unwrap();
+            assert_eq!(ownership, OWNERSHIP.item.load(deps.as_ref().storage).unwrap());
+            assert_eq!(ownership.pending_owner, Some(jake.clone()));
+
+            // converting back yields the unchecked ownership, with the owner's
+            // activity counted from the initialization
+            assert_eq!(
+                Ownership::<String>::from(ownership),
+                Ownership {
+                    last_activity: Some(Activity::at(&env.block)),
+                    ..unchecked
+                },
+            );
+        }
+
+        // the pending owner can accept the initialized transfer
+        {
+            let ownership = OWNERSHIP
+                .update_ownership(
+                    deps.as_mut(),
+                    &mock_block_at_height(12345),
+                    &jake,
+                    Action::AcceptOwnership,
+                )
+                .unwrap();
+            assert_eq!(ownership.owner, Some(jake));
+        }
+    }
+
+    #[test]
+    fn asserting_ownership() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let [larry, jake, _] = mock_addresses(&deps.api);
+
+        // case 1. owner has not renounced
+        {
+            OWNERSHIP.initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str())).unwrap();
+
+            let res = OWNERSHIP.assert_owner(deps.as_ref().storage, &env.block, &larry);
+            assert!(res.is_ok());
+
+            let res = OWNERSHIP.assert_owner(deps.as_ref().storage, &env.block, &jake);
+            assert_eq!(res.unwrap_err(), OwnershipError::NotOwner);
+        }
+
+        // case 2. owner has renounced
+        {
+            OWNERSHIP
+                .update_ownership(
+                    deps.as_mut(),
+                    &mock_block_at_height(12345),
+                    &larry,
+                    Action::RenounceOwnership,
+                )
+                .unwrap();
+
+            let res = OWNERSHIP.assert_owner(deps.as_ref().storage, &env.block, &larry);
+            assert_eq!(res.unwrap_err(), OwnershipError::NoOwner);
+        }
+    }
+
+    #[test]
+    fn transferring_ownership() {
+        let mut deps = mock_dependencies();
+        let [larry, jake, pumpkin] = mock_addresses(&deps.api);
+
+        OWNERSHIP.initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str())).unwrap();
+
+        // non-owner cannot transfer ownership
+        {
+            let err = OWNERSHIP
+                .update_ownership(
+                    deps.as_mut(),
+                    &mock_block_at_height(12345),
+                    &jake,
+                    Action::TransferOwnership {
+                        new_owner: pumpkin.to_string(),
+                        expiry: None,
+                        expires_in: None,
+                        delay: None,
+                        pubkey: None,
+                        price: None,
+                    },
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::NotOwner);
+        }
+
+        // owner properly transfers ownership
+        {
+            let ownership = OWNERSHIP
+                .update_ownership(
+                    deps.as_mut(),
+                    &mock_block_at_height(12345),
+                    &larry,
+                    Action::TransferOwnership {
+                        new_owner: pumpkin.to_string(),
+                        expiry: Some(Expiration::AtHeight(42069)),
+                        expires_in: None,
+                        delay: None,
+                        pubkey: None,
+                        price: None,
+                    },
+                )
+                .unwrap();
+            assert_eq!(
+                ownership,
+                Ownership {
+                    owner: Some(larry),
+                    pending_owner: Some(pumpkin),
+                    pending_expiry: Some(Expiration::AtHeight(42069)),
+                    pending_accept_after: None,
+                    pending_pubkey: None,
+                    pending_price: None,
+                    pending_renounce: None,
+                    lease_expiry: None,
+                    fallback_owner: None,
+                    recovery_address: None,
+                    last_activity: Some(Activity::at(&mock_block_at_height(12345))),
+                },
+            );
+
+            let saved_ownership = OWNERSHIP.item.load(deps.as_ref().storage).unwrap();
+            assert_eq!(saved_ownership, ownership);
+        }
+    }
+
+    #[test]
+    fn accepting_ownership() {
+        let mut deps = mock_dependencies();
+        let [larry, jake, pumpkin] = mock_addresses(&deps.api);
+
+        OWNERSHIP
+            .initialize_owner(&mut deps.storage, &deps.api.clone(), Some(larry.as_str()))
+            .unwrap();
+
+        // cannot accept ownership when there isn't a pending ownership transfer
+        {
+            let err = OWNERSHIP
+                .update_ownership(
+                    deps.as_mut(),
+                    &mock_block_at_height(12345),
+                    &pumpkin,
+                    Action::AcceptOwnership,
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::TransferNotFound);
+        }
+
+        OWNERSHIP
+            .update_ownership(
+                deps.as_mut(),
+                &mock_block_at_height(12345),
+                &larry,
+                Action::TransferOwnership {
+                    new_owner: pumpkin.to_string(),
+                    expiry: Some(Expiration::AtHeight(42069)),
+                    expires_in: None,
+                    delay: None,
+                    pubkey: None,
+                    price: None,
+                },
+            )
+            .unwrap();
+
+        // non-pending owner cannot accept ownership
+        {
+            let err = OWNERSHIP
+                .update_ownership(
+                    deps.as_mut(),
+                    &mock_block_at_height(12345),
+                    &jake,
+                    Action::AcceptOwnership,
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::NotPendingOwner);
+        }
+
+        // cannot accept ownership if deadline has passed
+        {
+            let err = OWNERSHIP
+                .update_ownership(
+                    deps.as_mut(),
+                    &mock_block_at_height(69420),
+                    &pumpkin,
+                    Action::AcceptOwnership,
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::TransferExpired);
+        }
+
+        // pending owner properly accepts ownership before deadline
+        {
+            let ownership = OWNERSHIP
+                .update_ownership(
+                    deps.as_mut(),
+                    &mock_block_at_height(10000),
+                    &pumpkin,
+                    Action::AcceptOwnership,
+                )
+                .unwrap();
+            assert_eq!(
+                ownership,
+                Ownership {
+                    owner: Some(pumpkin),
+                    pending_owner: None,
+                    pending_expiry: None,
+                    pending_accept_after: None,
+                    pending_pubkey: None,
+                    pending_price: None,
+                    pending_renounce: None,
+                    lease_expiry: None,
+                    fallback_owner: None,
+                    recovery_address: None,
+                    last_activity: Some(Activity::at(&mock_block_at_height(10000))),
+                },
+            );
+
+            let saved_ownership = OWNERSHIP.item.load(deps.as_ref().storage).unwrap();
+            assert_eq!(saved_ownership, ownership);
+        }
+    }
+
+    #[test]
+    fn accepting_timelocked_ownership() {
+        let mut deps = mock_dependencies();
+        let [larry, _, pumpkin] = mock_addresses(&deps.api);
+
+        let store = OwnershipStore::new(OWNERSHIP_KEY).with_transfer_delay(Duration::Height(100));
+        store.initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str())).unwrap();
+
+        // cannot propose a transfer with a delay shorter than the minimum
+        for delay in [Duration::Height(99), Duration::Time(100)] {
+            let err = store
+                .update_ownership(
+                    deps.as_mut(),
+                    &mock_block_at_height(12345),
+                    &larry,
+                    Action::TransferOwnership {
+                        new_owner: pumpkin.to_string(),
+                        expiry: None,
+                        expires_in: None,
+                        delay: Some(delay),
+                        pubkey: None,
+                        price: None,
+                    },
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::TransferDelayTooShort(Duration::Height(100)));
+        }
+
+        // the store's minimum delay is used if the transfer doesn't provide one
+        {
+            let ownership = store
+                .update_ownership(
+                    deps.as_mut(),
+                    &mock_block_at_height(12345),
+                    &larry,
+                    Action::TransferOwnership {
+                        new_owner: pumpkin.to_string(),
+                        expiry: None,
+                        expires_in: None,
+                        delay: None,
+                        pubkey: None,
+                        price: None,
+                    },
+                )
+                .unwrap();
+            assert_eq!(ownership.pending_accept_after, Some(Expiration::AtHeight(12445)));
+        }
+
+        // a transfer must not expire before it can be accepted
+        for (expiry, expires_in) in
+            [(Some(Expiration::AtHeight(12445)), None), (None, Some(Duration::Height(50)))]
+        {
+            let err = store
+                .update_ownership(
+                    deps.as_mut(),
+                    &mock_block_at_height(12345),
+                    &larry,
+                    Action::TransferOwnership {
+                        new_owner: pumpkin.to_string(),
+                        expiry,
+                        expires_in,
+                        delay: None,
+                        pubkey: None,
+                        price: None,
+                    },
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::TransferNeverAcceptable(Expiration::AtHeight(12445)));
+        }
+
+        // a transfer may be delayed for longer than the minimum
+        store
+            .update_ownership(
+                deps.as_mut(),
+                &mock_block_at_height(12345),
+                &larry,
+                Action::TransferOwnership {
+                    new_owner: pumpkin.to_string(),
+                    expiry: None,
+                    expires_in: None,
+                    delay: Some(Duration::Height(200)),
+                    pubkey: None,
+                    price: None,
+                },
+            )
+            .unwrap();
+
+        // cannot accept ownership before the delay has elapsed
+        {
+            let err = store
+                .update_ownership(
+                    deps.as_mut(),
+                    &mock_block_at_height(12544),
+                    &pumpkin,
+                    Action::AcceptOwnership,
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::TransferNotYetAcceptable(Expiration::AtHeight(12545)));
+        }
+
+        // pending owner properly accepts ownership after the delay
+        {
+            let ownership = store
+                .update_ownership(
+                    deps.as_mut(),
+                    &mock_block_at_height(12545),
+                    &pumpkin,
+                    Action::AcceptOwnership,
+                )
+                .unwrap();
+            assert_eq!(
+                ownership,
+                Ownership {
+                    owner: Some(pumpkin),
+                    pending_owner: None,
+                    pending_expiry: None,
+                    pending_accept_after: None,
+                    pending_pubkey: None,
+                    pending_price: None,
+                    pending_renounce: None,
+                    lease_expiry: None,
+                    fallback_owner: None,
+                    recovery_address: None,
+                    last_activity: Some(Activity::at(&mock_block_at_height(12545))),
+                },
+            );
+        }
+    }
+
+    #[test]
+    fn renouncing_ownership() {
+        let mut deps = mock_dependencies();
+        let [larry, jake, pumpkin] = mock_addresses(&deps.api);
+
+        let ownership = Ownership {
+            owner: Some(larry.clone()),
+            pending_owner: Some(pumpkin),
+            pending_expiry: None,
+            pending_accept_after: None,
+            pending_pubkey: None,
+            pending_price: None,
+            pending_renounce: None,
+            lease_expiry: None,
+            fallback_owner: None,
+            recovery_address: None,
+            last_activity: None,
+        };
+        OWNERSHIP.item.save(deps.as_mut().storage, &ownership).unwrap();
+
+        // non-owner cannot renounce
+        {
+            let err = OWNERSHIP
+                .update_ownership(
+                    deps.as_mut(),
+                    &mock_block_at_height(12345),
+                    &jake,
+                    Action::RenounceOwnership,
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::NotOwner);
+        }
+
+        // owner properly renounces
+        {
+            let ownership = OWNERSHIP
+                .update_ownership(
+                    deps.as_mut(),
+                    &mock_block_at_height(12345),
+                    &larry,
+                    Action::RenounceOwnership,
+                )
+                .unwrap();
+
+            // ownership returned is same as ownership stored.
+            assert_eq!(ownership, OWNERSHIP.item.load(deps.as_ref().storage).unwrap());
+
+            assert_eq!(
+                ownership,
+                Ownership {
+                    owner: None,
+                    pending_owner: None,
+                    pending_expiry: None,
+                    pending_accept_after: None,
+                    pending_pubkey: None,
+                    pending_price: None,
+                    pending_renounce: None,
+                    lease_expiry: None,
+                    fallback_owner: None,
+                    recovery_address: None,
+                    last_activity: None,
+                },
+            );
+        }
+
+        // cannot renounce twice
+        {
+            let err = OWNERSHIP
+                .update_ownership(
+                    deps.as_mut(),
+                    &mock_block_at_height(12345),
+                    &larry,
+                    Action::RenounceOwnership,
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::NoOwner);
+        }
+    }
+
+    #[test]
+    fn renouncing_ownership_in_two_steps() {
+        const OWNERSHIP: OwnershipStore =
+            OwnershipStore::new(OWNERSHIP_KEY).with_renounce_delay(Duration::Height(100));
+
+        let mut deps = mock_dependencies();
+        let [larry, jake, _] = mock_addresses(&deps.api);
+
+        OWNERSHIP.initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str())).unwrap();
+
+        let mut update = |sender: &Addr, height: u64, action: Action| {
+            OWNERSHIP.update_ownership(deps.as_mut(), &mock_block_at_height(height), sender, action)
+        };
+
+        // cannot renounce in a single step
+        {
+            let err = update(&larry, 12345, Action::RenounceOwnership).unwrap_err();
+            assert_eq!(err, OwnershipError::RenounceRequiresConfirmation);
+        }
+
+        // cannot confirm or cancel a renouncement that hasn't been proposed
+        {
+            let err = update(&larry, 12345, Action::ConfirmRenounce).unwrap_err();
+            assert_eq!(err, OwnershipError::RenounceNotProposed);
+
+            let err = update(&larry, 12345, Action::CancelRenounce).unwrap_err();
+            assert_eq!(err, OwnershipError::RenounceNotProposed);
+        }
+
+        // only the owner can propose, with at least the minimum delay
+        {
+            let err = update(
+                &jake,
+                12345,
+                Action::ProposeRenounce {
+                    delay: None,
+                },
+            )
+            .unwrap_err();
+            assert_eq!(err, OwnershipError::NotOwner);
+
+            let err = update(
+                &larry,
+                12345,
+                Action::ProposeRenounce {
+                    delay: Some(Duration::Height(99)),
+                },
+            )
+            .unwrap_err();
+            assert_eq!(err, OwnershipError::RenounceDelayTooShort(Duration::Height(100)));
+        }
+
+        // owner properly proposes and cancels a renouncement
+        {
+            let ownership = update(
+                &larry,
+                12345,
+                Action::ProposeRenounce {
+                    delay: None,
+                },
+            )
+            .unwrap();
+            assert_eq!(ownership.pending_renounce, Some(Expiration::AtHeight(12445)));
+            assert_eq!(ownership.owner, Some(larry.clone()));
+
+            let ownership = update(&larry, 12346, Action::CancelRenounce).unwrap();
+            assert_eq!(ownership.pending_renounce, None);
+        }
+
+        // owner cannot confirm before the delay has elapsed
+        {
+            update(
+                &larry,
+                12345,
+                Action::ProposeRenounce {
+                    delay: None,
+                },
+            )
+            .unwrap();
+
+            let err = update(&larry, 12444, Action::ConfirmRenounce).unwrap_err();
+            assert_eq!(err, OwnershipError::RenounceNotYetConfirmable(Expiration::AtHeight(12445)));
+        }
+
+        // owner properly confirms the renouncement after the delay
+        {
+            let ownership = update(&larry, 12445, Action::ConfirmRenounce).unwrap();
+            assert_eq!(
+                ownership,
+                Ownership {
+                    owner: None,
+                    pending_owner: None,
+                    pending_expiry: None,
+                    pending_accept_after: None,
+                    pending_pubkey: None,
+                    pending_price: None,
+                    pending_renounce: None,
+                    lease_expiry: None,
+                    fallback_owner: None,
+                    recovery_address: None,
+                    last_activity: Some(Activity::at(&mock_block_at_height(12345))),
+                },
+            );
+        }
+    }
+
+    #[test]
+    fn selling_ownership() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let [larry, _, pumpkin] = mock_addresses(&deps.api);
+
+        OWNERSHIP.initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str())).unwrap();
+
+        let transfer = |price: Vec<Coin>| Action::TransferOwnership {
+            new_owner: pumpkin.to_string(),
+            expiry: None,
+            expires_in: None,
+            delay: None,
+            pubkey: None,
+            price: Some(price),
+        };
+
+        // the price must be non-empty, without duplicate denoms or zero amounts
+        {
+            for price in [
+                vec![],
+                vec![Coin::new(0u128, "uatom")],
+                vec![Coin::new(100u128, "uatom"), Coin::new(100u128, "uatom")],
+            ] {
+                let err = OWNERSHIP
+                    .execute_update_ownership(
+                        deps.as_mut(),
+                        &env,
+                        &message_info(&larry, &[]),
+                        transfer(price),
+                    )
+                    .unwrap_err();
+                assert_eq!(err, OwnershipError::InvalidPrice);
+            }
+        }
+
+        // the price can only be paid through `execute_update_ownership`
+        {
+            let err = OWNERSHIP
+                .update_ownership(
+                    deps.as_mut(),
+                    &env.block,
+                    &larry,
+                    transfer(vec![Coin::new(100u128, "uatom")]),
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::PricedTransferUnsupported);
+        }
+
+        let res = OWNERSHIP
+            .execute_update_ownership(
+                deps.as_mut(),
+                &env,
+                &message_info(&larry, &[]),
+                transfer(vec![Coin::new(100u128, "uosmo"), Coin::new(50u128, "uatom")]),
+            )
+            .unwrap();
+        assert_eq!(
+            res.events[0].attributes.last().unwrap(),
+            Attribute::new("pending_price", "50uatom,100uosmo"),
+        );
+
+        // the funds sent must match the price exactly
+        {
+            let price =
+                Coins::try_from(vec![Coin::new(50u128, "uatom"), Coin::new(100u128, "uosmo")])
+                    .unwrap();
+
+            for funds in [
+                vec![],
+                vec![Coin::new(50u128, "uatom")],
+                vec![Coin::new(50u128, "uatom"), Coin::new(101u128, "uosmo")],
+                vec![
+                    Coin::new(50u128, "uatom"),
+                    Coin::new(100u128, "uosmo"),
+                    Coin::new(1u128, "ujuno"),
+                ],
+            ] {
+                let err = OWNERSHIP
+                    .execute_update_ownership(
+                        deps.as_mut(),
+                        &env,
+                        &message_info(&pumpkin, &funds),
+                        Action::AcceptOwnership,
+                    )
+                    .unwrap_err();
+                assert_eq!(err, OwnershipError::PriceMismatch(price.clone()));
+            }
+
+            // priced transfers can't be accepted without the funds being forwarded
+            let err = OWNERSHIP
+                .update_ownership(deps.as_mut(), &env.block, &pumpkin, Action::AcceptOwnership)
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::PriceMismatch(price));
+        }
+
+        // pending owner properly pays for the ownership, in any order
+        {
+            let funds = [Coin::new(100u128, "uosmo"), Coin::new(50u128, "uatom")];
+            let res = OWNERSHIP
+                .execute_update_ownership(
+                    deps.as_mut(),
+                    &env,
+                    &message_info(&pumpkin, &funds),
+                    Action::AcceptOwnership,
+                )
+                .unwrap();
+            assert_eq!(
+                res.messages[0].msg,
+                BankMsg::Send {
+                    to_address: larry.to_string(),
+                    amount: vec![Coin::new(50u128, "uatom"), Coin::new(100u128, "uosmo")],
+                }
+                .into(),
+            );
+
+            let ownership = OWNERSHIP.get_ownership(deps.as_ref().storage, &env.block).unwrap();
+            assert_eq!(ownership.owner, Some(pumpkin.clone()));
+            assert_eq!(ownership.pending_price, None);
+        }
+
+        // funds are rejected unless they pay for a priced transfer
+        {
+            let funds = [Coin::new(100u128, "uatom")];
+            let transfer = Action::TransferOwnership {
+                new_owner: larry.to_string(),
+                expiry: None,
+                expires_in: None,
+                delay: None,
+                pubkey: None,
+                price: None,
+            };
+
+            let err = OWNERSHIP
+                .execute_update_ownership(
+                    deps.as_mut(),
+                    &env,
+                    &message_info(&pumpkin, &funds),
+                    transfer.clone(),
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::UnexpectedFunds);
+
+            OWNERSHIP
+                .execute_update_ownership(
+                    deps.as_mut(),
+                    &env,
+                    &message_info(&pumpkin, &[]),
+                    transfer,
+                )
+                .unwrap();
+
+            let err = OWNERSHIP
+                .execute_update_ownership(
+                    deps.as_mut(),
+                    &env,
+                    &message_info(&larry, &funds),
+                    Action::AcceptOwnership,
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::UnexpectedFunds);
+        }
+    }
+
+    #[test]
+    fn leasing_ownership() {
+        let mut deps = mock_dependencies();
+        let [larry, jake, pumpkin] = mock_addresses(&deps.api);
+
+        OWNERSHIP.initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str())).unwrap();
+
+        let mut update = |sender: &Addr, height: u64, action: Action| {
+            OWNERSHIP.update_ownership(deps.as_mut(), &mock_block_at_height(height), sender, action)
+        };
+
+        // cannot extend or end a lease that doesn't exist
+        {
+            let err = update(
+                &larry,
+                12345,
+                Action::ExtendLease {
+                    expiry: Expiration::AtHeight(20000),
+                },
+            )
+            .unwrap_err();
+            assert_eq!(err, OwnershipError::LeaseNotFound);
+
+            let err = update(&larry, 12345, Action::EndLease).unwrap_err();
+            assert_eq!(err, OwnershipError::LeaseNotFound);
+        }
+
+        // only the owner can lease, with an expiry in the future
+        {
+            let set_lease = |expiry| Action::SetLease {
+                expiry,
+                fallback_owner: larry.to_string(),
+            };
+
+            let err = update(&jake, 12345, set_lease(Expiration::AtHeight(20000))).unwrap_err();
+            assert_eq!(err, OwnershipError::NotOwner);
+
+            let err = update(&larry, 12345, set_lease(Expiration::AtHeight(12345))).unwrap_err();
+            assert_eq!(err, OwnershipError::ExpiryInPast(Expiration::AtHeight(12345)));
+        }
 
-    use super::*;
+        // owner properly leases the ownership, which carries over to the lessee
+        {
+            let ownership = update(
+                &larry,
+                12345,
+                Action::SetLease {
+                    expiry: Expiration::AtHeight(20000),
+                    fallback_owner: larry.to_string(),
+                },
+            )
+            .unwrap();
+            assert_eq!(ownership.lease_expiry, Some(Expiration::AtHeight(20000)));
+            assert_eq!(ownership.fallback_owner, Some(larry.clone()));
 
-    fn mock_addresses(api: &MockApi) -> [Addr; 3] {
-        [
-            api.addr_make("larry"),
-            api.addr_make("jake"),
-            api.addr_make("pumpkin"),
-        ]
-    }
+            let err = update(
+                &larry,
+                12345,
+                Action::SetLease {
+                    expiry: Expiration::AtHeight(30000),
+                    fallback_owner: larry.to_string(),
+                },
+            )
+            .unwrap_err();
+            assert_eq!(err, OwnershipError::LeaseAlreadyExists);
 
-    fn mock_block_at_height(height: u64) -> BlockInfo {
-        BlockInfo {
-            height,
-            time: Timestamp::from_seconds(10000),
-            chain_id: "".into(),
+            update(
+                &larry,
+                12345,
+                Action::TransferOwnership {
+                    new_owner: jake.to_string(),
+                    expiry: None,
+                    expires_in: None,
+                    delay: None,
+                    pubkey: None,
+                    price: None,
+                },
+            )
+            .unwrap();
+            let ownership = update(&jake, 12346, Action::AcceptOwnership).unwrap();
+            assert_eq!(ownership.owner, Some(jake.clone()));
+            assert_eq!(ownership.lease_expiry, Some(Expiration::AtHeight(20000)));
         }
-    }
 
-    #[test]
-    fn initializing_ownership() {
-        let mut deps = mock_dependencies();
-        let [larry, _, _] = mock_addresses(&deps.api);
+        // only the owner or fallback can extend the lease, to a later expiry
+        {
+            let extend_lease = |expiry| Action::ExtendLease {
+                expiry,
+            };
 
-        let ownership =
-            OWNERSHIP.initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str())).unwrap();
+            let err =
+                update(&pumpkin, 12346, extend_lease(Expiration::AtHeight(25000))).unwrap_err();
+            assert_eq!(err, OwnershipError::NotOwnerOrFallback);
 
-        // ownership returned is same as ownership stored.
-        assert_eq!(ownership, OWNERSHIP.item.load(deps.as_ref().storage).unwrap());
+            let err = update(&jake, 12346, extend_lease(Expiration::AtHeight(15000))).unwrap_err();
+            assert_eq!(err, OwnershipError::LeaseNotExtended(Expiration::AtHeight(20000)));
 
-        assert_eq!(
-            ownership,
-            Ownership {
-                owner: Some(larry),
-                pending_owner: None,
-                pending_expiry: None,
-            },
-        );
-    }
+            let ownership = update(&larry, 12346, extend_lease(Expiration::AtHeight(25000)));
+            assert_eq!(ownership.unwrap().lease_expiry, Some(Expiration::AtHeight(25000)));
+        }
 
-    #[test]
-    fn initialize_ownership_no_owner() {
-        let mut deps = mock_dependencies();
-        let ownership = OWNERSHIP.initialize_owner(&mut deps.storage, &deps.api, None).unwrap();
-        assert_eq!(
-            ownership,
-            Ownership {
-                owner: None,
-                pending_owner: None,
-                pending_expiry: None,
-            },
-        );
-    }
+        // the fallback takes over once the lease has ended, without a transaction
+        {
+            let block = mock_block_at_height(24999);
+            assert!(OWNERSHIP.assert_owner(deps.as_ref().storage, &block, &jake).is_ok());
 
-    #[test]
-    fn asserting_ownership() {
-        let mut deps = mock_dependencies();
-        let [larry, jake, _] = mock_addresses(&deps.api);
+            let block = mock_block_at_height(25000);
+            let err = OWNERSHIP.assert_owner(deps.as_ref().storage, &block, &jake).unwrap_err();
+            assert_eq!(err, OwnershipError::NotOwner);
+            assert!(OWNERSHIP.is_owner(deps.as_ref().storage, &block, &larry).unwrap());
 
-        // case 1. owner has not renounced
-        {
-            OWNERSHIP.initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str())).unwrap();
+            let ownership = OWNERSHIP.get_ownership(deps.as_ref().storage, &block).unwrap();
+            assert_eq!(ownership.owner, Some(larry.clone()));
+            assert_eq!(ownership.lease_expiry, None);
 
-            let res = OWNERSHIP.assert_owner(deps.as_ref().storage, &larry);
-            assert!(res.is_ok());
+            // until an action is taken, the lessee is still the stored owner
+            assert!(OWNERSHIP.assert_stored_owner(deps.as_ref().storage, &jake).is_ok());
+        }
 
-            let res = OWNERSHIP.assert_owner(deps.as_ref().storage, &jake);
-            assert_eq!(res.unwrap_err(), OwnershipError::NotOwner);
+        let mut update = |sender: &Addr, height: u64, action: Action| {
+            OWNERSHIP.update_ownership(deps.as_mut(), &mock_block_at_height(height), sender, action)
+        };
+
+        // the lessee can no longer act on the ownership
+        {
+            let err = update(&jake, 25000, Action::RenounceOwnership).unwrap_err();
+            assert_eq!(err, OwnershipError::NotOwner);
         }
 
-        // case 2. owner has renounced
+        // the fallback properly ends a lease early
         {
-            OWNERSHIP.renounce_ownership(deps.as_mut().storage, &larry).unwrap();
+            update(
+                &larry,
+                25000,
+                Action::SetLease {
+                    expiry: Expiration::AtHeight(30000),
+                    fallback_owner: pumpkin.to_string(),
+                },
+            )
+            .unwrap();
 
-            let res = OWNERSHIP.assert_owner(deps.as_ref().storage, &larry);
-            assert_eq!(res.unwrap_err(), OwnershipError::NoOwner);
+            let ownership = update(&pumpkin, 25001, Action::EndLease).unwrap();
+            assert_eq!(ownership.owner, Some(pumpkin));
+            assert_eq!(ownership.lease_expiry, None);
+            assert_eq!(ownership.fallback_owner, None);
         }
     }
 
     #[test]
-    fn transferring_ownership() {
+    fn canceling_and_rejecting_transfer() {
         let mut deps = mock_dependencies();
         let [larry, jake, pumpkin] = mock_addresses(&deps.api);
 
         OWNERSHIP.initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str())).unwrap();
 
-        // non-owner cannot transfer ownership
+        // cannot cancel or reject when there isn't a pending ownership transfer
         {
             let err = OWNERSHIP
                 .update_ownership(
                     deps.as_mut(),
                     &mock_block_at_height(12345),
-                    &jake,
-                    Action::TransferOwnership {
-                        new_owner: pumpkin.to_string(),
-                        expiry: None,
-                    },
+                    &larry,
+                    Action::CancelTransfer,
                 )
                 .unwrap_err();
-            assert_eq!(err, OwnershipError::NotOwner);
-        }
+            assert_eq!(err, OwnershipError::TransferNotFound);
 
-        // owner properly transfers ownership
-        {
-            let ownership = OWNERSHIP
+            let err = OWNERSHIP
                 .update_ownership(
                     deps.as_mut(),
                     &mock_block_at_height(12345),
-                    &larry,
-                    Action::TransferOwnership {
-                        new_owner: pumpkin.to_string(),
-                        expiry: Some(Expiration::AtHeight(42069)),
-                    },
+                    &pumpkin,
+                    Action::RejectTransfer,
                 )
-                .unwrap();
-            assert_eq!(
-                ownership,
-                Ownership {
-                    owner: Some(larry),
-                    pending_owner: Some(pumpkin),
-                    pending_expiry: Some(Expiration::AtHeight(42069)),
-                },
-            );
-
-            let saved_ownership = OWNERSHIP.item.load(deps.as_ref().storage).unwrap();
-            assert_eq!(saved_ownership, ownership);
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::TransferNotFound);
         }
-    }
-
-    #[test]
-    fn accepting_ownership() {
-        let mut deps = mock_dependencies();
-        let [larry, jake, pumpkin] = mock_addresses(&deps.api);
 
+        let transfer = Action::TransferOwnership {
+            new_owner: pumpkin.to_string(),
+            expiry: Some(Expiration::AtHeight(42069)),
+            expires_in: None,
+            delay: None,
+            pubkey: None,
+            price: None,
+        };
         OWNERSHIP
-            .initialize_owner(&mut deps.storage, &deps.api.clone(), Some(larry.as_str()))
+            .update_ownership(deps.as_mut(), &mock_block_at_height(12345), &larry, transfer.clone())
             .unwrap();
 
-        // cannot accept ownership when there isn't a pending ownership transfer
+        // only the owner can cancel, and only the pending owner can reject
         {
             let err = OWNERSHIP
                 .update_ownership(
                     deps.as_mut(),
                     &mock_block_at_height(12345),
                     &pumpkin,
-                    Action::AcceptOwnership,
+                    Action::CancelTransfer,
                 )
                 .unwrap_err();
-            assert_eq!(err, OwnershipError::TransferNotFound);
-        }
-
-        OWNERSHIP
-            .transfer_ownership(
-                &deps.api.clone().clone(),
-                deps.as_mut().storage,
-                &larry,
-                pumpkin.as_str(),
-                Some(Expiration::AtHeight(42069)),
-            )
-            .unwrap();
+            assert_eq!(err, OwnershipError::NotOwner);
 
-        // non-pending owner cannot accept ownership
-        {
             let err = OWNERSHIP
                 .update_ownership(
                     deps.as_mut(),
                     &mock_block_at_height(12345),
                     &jake,
-                    Action::AcceptOwnership,
+                    Action::RejectTransfer,
                 )
                 .unwrap_err();
             assert_eq!(err, OwnershipError::NotPendingOwner);
         }
 
-        // cannot accept ownership if deadline has passed
+        let expected = Ownership {
+            owner: Some(larry.clone()),
+            pending_owner: None,
+            pending_expiry: None,
+            pending_accept_after: None,
+            pending_pubkey: None,
+            pending_price: None,
+            pending_renounce: None,
+            lease_expiry: None,
+            fallback_owner: None,
+            recovery_address: None,
+            last_activity: Some(Activity::at(&mock_block_at_height(12345))),
+        };
+
+        // owner properly cancels the transfer
         {
-            let err = OWNERSHIP
+            let ownership = OWNERSHIP
                 .update_ownership(
                     deps.as_mut(),
-                    &mock_block_at_height(69420),
-                    &pumpkin,
-                    Action::AcceptOwnership,
+                    &mock_block_at_height(12345),
+                    &larry,
+                    Action::CancelTransfer,
                 )
-                .unwrap_err();
-            assert_eq!(err, OwnershipError::TransferExpired);
+                .unwrap();
+            assert_eq!(ownership, expected);
+            assert_eq!(OWNERSHIP.item.load(deps.as_ref().storage).unwrap(), expected);
         }
 
-        // pending owner properly accepts ownership before deadline
+        OWNERSHIP
+            .update_ownership(deps.as_mut(), &mock_block_at_height(12345), &larry, transfer)
+            .unwrap();
+
+        // pending owner properly rejects the transfer
         {
             let ownership = OWNERSHIP
                 .update_ownership(
                     deps.as_mut(),
-                    &mock_block_at_height(10000),
+                    &mock_block_at_height(12345),
                     &pumpkin,
-                    Action::AcceptOwnership,
+                    Action::RejectTransfer,
                 )
                 .unwrap();
-            assert_eq!(
-                ownership,
-                Ownership {
-                    owner: Some(pumpkin),
-                    pending_owner: None,
-                    pending_expiry: None,
-                },
-            );
-
-            let saved_ownership = OWNERSHIP.item.load(deps.as_ref().storage).unwrap();
-            assert_eq!(saved_ownership, ownership);
+            assert_eq!(ownership, expected);
+            assert_eq!(OWNERSHIP.item.load(deps.as_ref().storage).unwrap(), expected);
         }
     }
 
     #[test]
-    fn renouncing_ownership() {
+    fn recording_history() {
         let mut deps = mock_dependencies();
         let [larry, jake, pumpkin] = mock_addresses(&deps.api);
 
-        let ownership = Ownership {
-            owner: Some(larry.clone()),
-            pending_owner: Some(pumpkin),
-            pending_expiry: None,
-        };
-        OWNERSHIP.item.save(deps.as_mut().storage, &ownership).unwrap();
-
-        // non-owner cannot renounce
+        // history can't be queried if it isn't recorded
         {
-            let err = OWNERSHIP
-                .update_ownership(
-                    deps.as_mut(),
-                    &mock_block_at_height(12345),
-                    &jake,
-                    Action::RenounceOwnership,
-                )
-                .unwrap_err();
-            assert_eq!(err, OwnershipError::NotOwner);
+            let err = OWNERSHIP.query_history(deps.as_ref().storage, None, None).unwrap_err();
+            assert_eq!(
+                err,
+                StdError::generic_err("[cw-ownable]: ownership history is not recorded"),
+            );
         }
 
-        // owner properly renounces
-        {
-            let ownership = OWNERSHIP
-                .update_ownership(
-                    deps.as_mut(),
-                    &mock_block_at_height(12345),
-                    &larry,
-                    Action::RenounceOwnership,
-                )
-                .unwrap();
+        let store = OwnershipStore::new(OWNERSHIP_KEY).with_history("ownership_history");
+        store.initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str())).unwrap();
 
-            // ownership returned is same as ownership stored.
-            assert_eq!(ownership, OWNERSHIP.item.load(deps.as_ref().storage).unwrap());
+        let initial = store.get_ownership(deps.as_ref().storage, &mock_env().block).unwrap();
+        let proposed = store
+            .update_ownership(
+                deps.as_mut(),
+                &mock_block_at_height(12345),
+                &larry,
+                Action::TransferOwnership {
+                    new_owner: pumpkin.to_string(),
+                    expiry: None,
+                    expires_in: None,
+                    delay: None,
+                    pubkey: None,
+                    price: None,
+                },
+            )
+            .unwrap();
+        let accepted = store
+            .update_ownership(
+                deps.as_mut(),
+                &mock_block_at_height(12346),
+                &pumpkin,
+                Action::AcceptOwnership,
+            )
+            .unwrap();
 
-            assert_eq!(
-                ownership,
-                Ownership {
-                    owner: None,
-                    pending_owner: None,
-                    pending_expiry: None,
+        // failed actions aren't recorded
+        store
+            .update_ownership(
+                deps.as_mut(),
+                &mock_block_at_height(12347),
+                &jake,
+                Action::RenounceOwnership,
+            )
+            .unwrap_err();
+
+        let renounced = store
+            .update_ownership(
+                deps.as_mut(),
+                &mock_block_at_height(12348),
+                &pumpkin,
+                Action::RenounceOwnership,
+            )
+            .unwrap();
+
+        let history = store.query_history(deps.as_ref().storage, None, None).unwrap();
+        assert_eq!(
+            history,
+            vec![
+                OwnershipChange {
+                    id: 1,
+                    height: 12345,
+                    time: Timestamp::from_seconds(10000),
+                    actor: larry,
+                    kind: ChangeKind::TransferProposed,
+                    previous_ownership: initial,
+                    new_ownership: proposed.clone(),
                 },
-            );
-        }
+                OwnershipChange {
+                    id: 2,
+                    height: 12346,
+                    time: Timestamp::from_seconds(10000),
+                    actor: pumpkin.clone(),
+                    kind: ChangeKind::TransferAccepted,
+                    previous_ownership: proposed,
+                    new_ownership: accepted.clone(),
+                },
+                OwnershipChange {
+                    id: 3,
+                    height: 12348,
+                    time: Timestamp::from_seconds(10000),
+                    actor: pumpkin,
+                    kind: ChangeKind::OwnershipRenounced,
+                    previous_ownership: accepted,
+                    new_ownership: renounced,
+                },
+            ],
+        );
 
-        // cannot renounce twice
-        {
-            let err = OWNERSHIP
-                .update_ownership(
-                    deps.as_mut(),
-                    &mock_block_at_height(12345),
-                    &larry,
-                    Action::RenounceOwnership,
-                )
-                .unwrap_err();
-            assert_eq!(err, OwnershipError::NoOwner);
-        }
+        let page = store.query_history(deps.as_ref().storage, Some(1), Some(1)).unwrap();
+        assert_eq!(page, history[1..2].to_vec());
+    }
+
+    #[test]
+    fn emitting_events() {
+        let mut deps = mock_dependencies();
+        let [larry, _, pumpkin] = mock_addresses(&deps.api);
+
+        OWNERSHIP.initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str())).unwrap();
+
+        let mut env = mock_env();
+        env.block = mock_block_at_height(12345);
+
+        let res = OWNERSHIP
+            .execute_update_ownership(
+                deps.as_mut(),
+                &env,
+                &message_info(&larry, &[]),
+                Action::TransferOwnership {
+                    new_owner: pumpkin.to_string(),
+                    expiry: Some(Expiration::AtHeight(42069)),
+                    expires_in: None,
+                    delay: None,
+                    pubkey: None,
+                    price: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            res.events,
+            vec![Event::new("ownership_transfer_proposed")
+                .add_attribute("actor", &larry)
+                .add_attribute("previous_owner", &larry)
+                .add_attribute("new_owner", &larry)
+                .add_attribute("pending_owner", &pumpkin)
+                .add_attribute("pending_expiry", "expiration height: 42069")],
+        );
+
+        let res = OWNERSHIP
+            .execute_update_ownership(
+                deps.as_mut(),
+                &env,
+                &message_info(&pumpkin, &[]),
+                Action::AcceptOwnership,
+            )
+            .unwrap();
+        assert_eq!(
+            res.events,
+            vec![Event::new("ownership_accepted")
+                .add_attribute("actor", &pumpkin)
+                .add_attribute("previous_owner", &larry)
+                .add_attribute("new_owner", &pumpkin)
+                .add_attribute("pending_owner", "none")
+                .add_attribute("pending_expiry", "none")],
+        );
     }
 
     #[test]
@@ -636,7 +3075,15 @@ mod tests {
             Ownership {
                 owner: Some("blue".to_string()),
                 pending_owner: None,
-                pending_expiry: Some(Expiration::Never {})
+                pending_expiry: Some(Expiration::Never {}),
+                pending_accept_after: None,
+                pending_pubkey: None,
+                pending_price: None,
+                pending_renounce: None,
+                lease_expiry: None,
+                fallback_owner: None,
+                recovery_address: None,
+                last_activity: None,
             }
             .into_attributes(),
             vec![
@@ -645,5 +3092,30 @@ mod tests {
                 Attribute::new("pending_expiry", "expiration: never")
             ],
         );
+
+        // the lease is included if the ownership is leased
+        assert_eq!(
+            Ownership {
+                owner: Some("blue".to_string()),
+                pending_owner: None,
+                pending_expiry: None,
+                pending_accept_after: None,
+                pending_pubkey: None,
+                pending_price: None,
+                pending_renounce: None,
+                lease_expiry: Some(Expiration::AtHeight(42069)),
+                fallback_owner: Some("red".to_string()),
+                recovery_address: None,
+                last_activity: None,
+            }
+            .into_attributes(),
+            vec![
+                Attribute::new("owner", "blue"),
+                Attribute::new("pending_owner", "none"),
+                Attribute::new("pending_expiry", "none"),
+                Attribute::new("lease_expiry", "expiration height: 42069"),
+                Attribute::new("fallback_owner", "red"),
+            ],
+        );
     }
 }
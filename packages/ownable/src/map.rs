@@ -0,0 +1,371 @@
+use cosmwasm_std::{Addr, Api, BlockInfo, DepsMut, Order, StdError, StdResult, Storage};
+use cw_storage_plus::{
+    Bound, Index, IndexList, IndexedMap, KeyDeserialize, MultiIndex, PrimaryKey,
+};
+
+use crate::{
+    check_owner, Action, Duration, ExpiryPolicy, Ownership, OwnershipError, Rules, TransferPolicy,
+};
+
+/// Secondary indexes of an [`OwnershipMap`]
+pub struct OwnershipIndexes<K> {
+    /// Index from an owner's address to the keys it owns. Entries whose
+    /// ownership has been renounced are indexed under an empty string.
+    pub owner: MultiIndex<'static, String, Ownership<Addr>, K>,
+}
+
+impl<K> IndexList<Ownership<Addr>> for OwnershipIndexes<K> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Ownership<Addr>>> + '_> {
+        let v: Vec<&dyn Index<Ownership<Addr>>> = vec![&self.owner];
+        Box::new(v.into_iter())
+    }
+}
+
+fn owner_index(_pk: &[u8], ownership: &Ownership<Addr>) -> String {
+    ownership.owner.as_ref().map(Addr::to_string).unwrap_or_default()
+}
+
+/// Ownerships of many resources within a contract, e.g. pools or vaults, each
+/// identified by a key and following the same rules as an [`OwnershipStore`].
+///
+/// [`OwnershipStore`]: crate::OwnershipStore
+pub struct OwnershipMap<K> {
+    pub map: IndexedMap<K, Ownership<Addr>, OwnershipIndexes<K>>,
+
+    /// The rules every ownership in the map follows, set by the `with_*`
+    /// methods.
+    rules: Rules,
+}
+
+impl<'a, K> OwnershipMap<K>
+where
+    K: PrimaryKey<'a>,
+{
+    pub const fn new(namespace: &'static str, owner_namespace: &'static str) -> Self {
+        Self {
+            map: IndexedMap::new(
+                namespace,
+                OwnershipIndexes {
+                    owner: MultiIndex::new(owner_index, namespace, owner_namespace),
+                },
+            ),
+            rules: Rules {
+                transfer_delay: None,
+                renounce_delay: None,
+                expiry_policy: ExpiryPolicy::new(),
+                transfer_policy: TransferPolicy::Any,
+                recovery_period: None,
+                // there's no entry point that verifies signatures or forwards
+                // the price to the owner
+                signed_acceptance: false,
+                priced_transfers: false,
+            },
+        }
+    }
+
+    /// Require every ownership transfer to wait for the given delay before it
+    /// can be accepted.
+    pub const fn with_transfer_delay(mut self, delay: Duration) -> Self {
+        self.rules.transfer_delay = Some(delay);
+        self
+    }
+
+    /// Require ownerships to be renounced in two steps, i.e. proposed and then
+    /// confirmed once the given delay has elapsed.
+    pub const fn with_renounce_delay(mut self, delay: Duration) -> Self {
+        self.rules.renounce_delay = Some(delay);
+        self
+    }
+
+    /// Require the expiry of every ownership transfer to follow the given
+    /// policy.
+    pub const fn with_expiry_policy(mut self, policy: ExpiryPolicy) -> Self {
+        self.rules.expiry_policy = policy;
+        self
+    }
+
+    /// Restrict who may be proposed as a new owner.
+    pub const fn with_transfer_policy(mut self, policy: TransferPolicy) -> Self {
+        self.rules.transfer_policy = policy;
+        self
+    }
+
+    /// Allow owners to set a recovery address, who may claim the ownership
+    /// once the owner has been inactive for the given period.
+    pub const fn with_recovery_period(mut self, period: Duration) -> Self {
+        self.rules.recovery_period = Some(period);
+        self
+    }
+}
+
+impl<'a, K> OwnershipMap<K>
+where
+    K: PrimaryKey<'a> + KeyDeserialize,
+    K::Output: 'static,
+{
+    /// Set the given address as the owner of the resource under the given key.
+    /// Return Err if the resource already has an ownership.
+    ///
+    /// This function is only intended to be used when the resource is created.
+    pub fn initialize_owner(
+        &self,
+        storage: &mut dyn Storage,
+        api: &dyn Api,
+        key: K,
+        owner: Option<&str>,
+    ) -> Result<Ownership<Addr>, OwnershipError> {
+        if self.map.has(storage, key.clone()) {
+            return Err(OwnershipError::OwnershipAlreadyExists);
+        }
+
+        let ownership = Ownership::new(owner.map(|h| api.addr_validate(h)).transpose()?);
+        self.map.save(storage, key, &ownership)?;
+        Ok(ownership)
+    }
+
+    /// Return Ok(true) if the resource has an owner at the given block and it's
+    /// the given address, taking into account whether the lease has ended.
+    /// Return Ok(false) if the resource doesn't have an owner, of if it does but
+    /// it's not the given address.
+    /// Return Err if fails to load the resource's ownership info from storage.
+    pub fn is_owner(
+        &self,
+        store: &dyn Storage,
+        block: &BlockInfo,
+        key: K,
+        addr: &Addr,
+    ) -> StdResult<bool> {
+        let ownership = self.get_ownership(store, block, key)?;
+        Ok(ownership.owner.as_ref() == Some(addr))
+    }
+
+    /// Assert that an account is the resource's owner at the given block,
+    /// taking into account whether the lease has ended.
+    pub fn assert_owner(
+        &self,
+        store: &dyn Storage,
+        block: &BlockInfo,
+        key: K,
+        sender: &Addr,
+    ) -> Result<(), OwnershipError> {
+        let ownership = self.get_ownership(store, block, key)?;
+        check_owner(&ownership, sender)
+    }
+
+    /// Update the resource's ownership info based on the given action.
+    /// Return the updated ownership.
+    pub fn update_ownership(
+        &self,
+        deps: DepsMut,
+        block: &BlockInfo,
+        sender: &Addr,
+        key: K,
+        action: Action,
+    ) -> Result<Ownership<Addr>, OwnershipError> {
+        self.map.update(deps.storage, key, |ownership| {
+            let ownership = ownership
+                .ok_or_else(|| StdError::not_found(std::any::type_name::<Ownership<Addr>>()))?;
+            self.rules.apply(deps.api, &deps.querier, block, sender, &[], ownership, action)
+        })
+    }
+
+    /// Get the ownership value of the resource as it stands at the given block,
+    /// i.e. with the fallback owner as the owner if the lease has ended.
+    pub fn get_ownership(
+        &self,
+        storage: &dyn Storage,
+        block: &BlockInfo,
+        key: K,
+    ) -> StdResult<Ownership<Addr>> {
+        Ok(self.map.load(storage, key)?.resolve_lease(block))
+    }
+
+    /// Delete the resource's ownership, e.g. when the resource is deleted.
+    pub fn remove(&self, storage: &mut dyn Storage, key: K) -> StdResult<()> {
+        self.map.remove(storage, key)
+    }
+
+    /// Return the ownerships of all resources, ordered by key.
+    pub fn query_ownerships(
+        &self,
+        storage: &dyn Storage,
+        start_after: Option<K>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<(K::Output, Ownership<Addr>)>> {
+        let start = start_after.map(Bound::exclusive);
+        cw_paginate::paginate_indexed_map(&self.map, storage, start, limit, |key, ownership| {
+            Ok((key, ownership))
+        })
+    }
+
+    /// Return the keys of the resources owned by the given address, ordered by
+    /// key.
+    ///
+    /// The index follows the owners as they're stored, so once a lease has
+    /// ended, the resource is still listed under the former owner, rather than
+    /// the fallback owner, until an action is taken on its ownership.
+    pub fn query_owned_by(
+        &self,
+        storage: &dyn Storage,
+        owner: &Addr,
+        start_after: Option<K>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<K::Output>> {
+        let start = start_after.map(Bound::exclusive);
+        let iter = self.map.idx.owner.prefix(owner.to_string()).range(
+            storage,
+            start,
+            None,
+            Order::Ascending,
+        );
+        cw_paginate::collect(iter, limit, |key, _| Ok(key))
+    }
+}
+
+//------------------------------------------------------------------------------
+// Tests
+//------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_dependencies;
+
+    use super::*;
+    use crate::{testing::mock_block_at_height, Expiration};
+
+    const POOLS: OwnershipMap<u64> =
+        OwnershipMap::new("pools", "pools__owner").with_transfer_delay(Duration::Height(10));
+
+    #[test]
+    fn transferring_keyed_ownership() {
+        let mut deps = mock_dependencies();
+        let larry = deps.api.addr_make("larry");
+        let jake = deps.api.addr_make("jake");
+
+        POOLS.initialize_owner(&mut deps.storage, &deps.api, 1, Some(larry.as_str())).unwrap();
+        POOLS.initialize_owner(&mut deps.storage, &deps.api, 2, Some(larry.as_str())).unwrap();
+
+        // cannot initialize an existing resource
+        {
+            let err = POOLS
+                .initialize_owner(&mut deps.storage, &deps.api, 1, Some(jake.as_str()))
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::OwnershipAlreadyExists);
+        }
+
+        // the owner of one resource can't act on another's
+        {
+            POOLS.initialize_owner(&mut deps.storage, &deps.api, 3, Some(jake.as_str())).unwrap();
+
+            let err = POOLS
+                .update_ownership(
+                    deps.as_mut(),
+                    &mock_block_at_height(12345),
+                    &larry,
+                    3,
+                    Action::RenounceOwnership,
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::NotOwner);
+            let block = mock_block_at_height(12345);
+            assert!(POOLS.assert_owner(deps.as_ref().storage, &block, 3, &larry).is_err());
+            assert!(POOLS.is_owner(deps.as_ref().storage, &block, 3, &jake).unwrap());
+        }
+
+        // the map's rules apply to each resource
+        {
+            POOLS
+                .update_ownership(
+                    deps.as_mut(),
+                    &mock_block_at_height(12345),
+                    &larry,
+                    1,
+                    Action::TransferOwnership {
+                        new_owner: jake.to_string(),
+                        expiry: None,
+                        expires_in: None,
+                        delay: None,
+                        pubkey: None,
+                        price: None,
+                    },
+                )
+                .unwrap();
+
+            let err = POOLS
+                .update_ownership(
+                    deps.as_mut(),
+                    &mock_block_at_height(12346),
+                    &jake,
+                    1,
+                    Action::AcceptOwnership,
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::TransferNotYetAcceptable(Expiration::AtHeight(12355)));
+
+            let ownership = POOLS
+                .update_ownership(
+                    deps.as_mut(),
+                    &mock_block_at_height(12355),
+                    &jake,
+                    1,
+                    Action::AcceptOwnership,
+                )
+                .unwrap();
+            assert_eq!(ownership.owner, Some(jake.clone()));
+        }
+
+        // the resource must exist
+        {
+            let err = POOLS
+                .update_ownership(
+                    deps.as_mut(),
+                    &mock_block_at_height(12355),
+                    &jake,
+                    4,
+                    Action::RenounceOwnership,
+                )
+                .unwrap_err();
+            assert!(matches!(err, OwnershipError::Std(StdError::NotFound { .. })));
+        }
+    }
+
+    #[test]
+    fn querying_keyed_ownership() {
+        let mut deps = mock_dependencies();
+        let larry = deps.api.addr_make("larry");
+        let jake = deps.api.addr_make("jake");
+
+        for (key, owner) in [(1, &larry), (2, &jake), (3, &larry), (4, &larry)] {
+            POOLS
+                .initialize_owner(&mut deps.storage, &deps.api, key, Some(owner.as_str()))
+                .unwrap();
+        }
+
+        let owned = POOLS.query_owned_by(deps.as_ref().storage, &larry, None, None).unwrap();
+        assert_eq!(owned, [1, 3, 4]);
+
+        let owned = POOLS.query_owned_by(deps.as_ref().storage, &larry, Some(1), Some(1)).unwrap();
+        assert_eq!(owned, [3]);
+
+        // the index follows ownership changes
+        POOLS
+            .update_ownership(
+                deps.as_mut(),
+                &mock_block_at_height(12345),
+                &larry,
+                3,
+                Action::RenounceOwnership,
+            )
+            .unwrap();
+        POOLS.remove(deps.as_mut().storage, 4).unwrap();
+
+        let owned = POOLS.query_owned_by(deps.as_ref().storage, &larry, None, None).unwrap();
+        assert_eq!(owned, [1]);
+
+        let ownerships = POOLS.query_ownerships(deps.as_ref().storage, Some(1), None).unwrap();
+        assert_eq!(ownerships.len(), 2);
+        assert_eq!(ownerships[0].0, 2);
+        assert_eq!(ownerships[0].1.owner, Some(jake));
+        assert_eq!(ownerships[1].1.owner, None);
+    }
+}
@@ -0,0 +1,213 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{from_json, Addr, Api, Storage};
+
+use crate::{Expiration, Ownership, OwnershipError, OwnershipStore, OWNERSHIP_KEY};
+
+/// Storage layouts from which the contract's ownership can be migrated
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LegacyLayout {
+    /// The admin of `cw_controllers::Admin`, stored as an `Option<Addr>` under
+    /// the contract's chosen key.
+    ControllersAdmin,
+
+    /// The ownership of cw-ownable prior to 2.0, stored under `OWNERSHIP_KEY`
+    /// with only the owner and the pending transfer.
+    CwOwnable,
+}
+
+/// The ownership of cw-ownable prior to 2.0. The current ownership is stored
+/// under the same key, but always with the fields added since, so it's told
+/// apart by rejecting unknown fields.
+#[cw_serde]
+struct LegacyOwnership {
+    owner: Option<String>,
+    pending_owner: Option<String>,
+    pending_expiry: Option<Expiration>,
+}
+
+/// Return the legacy layout present in storage, if any. `cw_controllers::Admin`
+/// is looked for under the given key, and takes precedence over cw-ownable.
+pub fn detect_legacy_layout(storage: &dyn Storage, admin_key: &str) -> Option<LegacyLayout> {
+    if storage.get(admin_key.as_bytes()).is_some() {
+        return Some(LegacyLayout::ControllersAdmin);
+    }
+
+    let data = storage.get(OWNERSHIP_KEY.as_bytes())?;
+    from_json::<LegacyOwnership>(data).is_ok().then_some(LegacyLayout::CwOwnable)
+}
+
+impl OwnershipStore {
+    /// Migrate the contract's ownership from a legacy storage layout into this
+    /// store. Return the detected layout and the migrated ownership.
+    ///
+    /// `cw_controllers::Admin` is looked for under `admin_key`. The legacy data
+    /// is removed once migrated, unless it's stored under this store's key.
+    ///
+    /// Errors if no legacy layout is present, or if this store already holds
+    /// an ownership other than the one being migrated.
+    ///
+    /// This function is only intended to be used during contract migration.
+    pub fn migrate_legacy_ownership(
+        &self,
+        storage: &mut dyn Storage,
+        api: &dyn Api,
+        admin_key: &str,
+    ) -> Result<(LegacyLayout, Ownership<Addr>), OwnershipError> {
+        let Some(layout) = detect_legacy_layout(storage, admin_key) else {
+            return Err(OwnershipError::LegacyOwnershipNotFound);
+        };
+
+        let legacy_key = match layout {
+            LegacyLayout::ControllersAdmin => admin_key,
+            LegacyLayout::CwOwnable => OWNERSHIP_KEY,
+        };
+        let in_place = self.item.as_slice() == legacy_key.as_bytes();
+
+        // refuse to overwrite an existing ownership
+        if !in_place && self.item.exists(storage) {
+            return Err(OwnershipError::OwnershipAlreadyExists);
+        }
+
+        // the key is known to exist, as the layout was detected from it
+        let data = storage.get(legacy_key.as_bytes()).unwrap_or_default();
+
+        let legacy: Ownership<String> = match layout {
+            LegacyLayout::ControllersAdmin => Ownership::new(from_json(&data)?),
+            LegacyLayout::CwOwnable => {
+                let legacy: LegacyOwnership = from_json(&data)?;
+                Ownership {
+                    pending_owner: legacy.pending_owner,
+                    pending_expiry: legacy.pending_expiry,
+                    ..Ownership::new(legacy.owner)
+                }
+            },
+        };
+        let ownership = legacy.validate(api)?;
+
+        if !in_place {
+            storage.remove(legacy_key.as_bytes());
+        }
+
+        self.item.save(storage, &ownership)?;
+
+        Ok((layout, ownership))
+    }
+}
+
+//------------------------------------------------------------------------------
+// Tests
+//------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cw_storage_plus::Item;
+
+    use super::*;
+    use crate::Expiration;
+
+    const ADMIN_KEY: &str = "admin";
+
+    #[test]
+    fn migrating_from_controllers_admin() {
+        let mut deps = mock_dependencies();
+        let larry = deps.api.addr_make("larry");
+        let store = OwnershipStore::new(OWNERSHIP_KEY);
+
+        // no legacy layout is present
+        {
+            let err = store
+                .migrate_legacy_ownership(&mut deps.storage, &deps.api, ADMIN_KEY)
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::LegacyOwnershipNotFound);
+        }
+
+        Item::<Option<Addr>>::new(ADMIN_KEY).save(&mut deps.storage, &Some(larry.clone())).unwrap();
+        assert_eq!(
+            detect_legacy_layout(&deps.storage, ADMIN_KEY),
+            Some(LegacyLayout::ControllersAdmin),
+        );
+
+        let (layout, ownership) =
+            store.migrate_legacy_ownership(&mut deps.storage, &deps.api, ADMIN_KEY).unwrap();
+        assert_eq!(layout, LegacyLayout::ControllersAdmin);
+        assert_eq!(ownership, Ownership::new(Some(larry)));
+        assert_eq!(store.get_ownership(&deps.storage, &mock_env().block).unwrap(), ownership);
+
+        // the legacy admin has been removed
+        assert!(deps.storage.get(ADMIN_KEY.as_bytes()).is_none());
+    }
+
+    #[test]
+    fn migrating_from_legacy_cw_ownable() {
+        let mut deps = mock_dependencies();
+        let [larry, jake] = [deps.api.addr_make("larry"), deps.api.addr_make("jake")];
+        let store = OwnershipStore::new("creator");
+
+        // cw-ownable prior to 2.0 stored only the owner and pending transfer
+        let legacy = format!(
+            r#"{{"owner":"{larry}","pending_owner":"{jake}","pending_expiry":{{"at_height":100}}}}"#,
+        );
+        deps.storage.set(OWNERSHIP_KEY.as_bytes(), legacy.as_bytes());
+
+        let (layout, ownership) =
+            store.migrate_legacy_ownership(&mut deps.storage, &deps.api, ADMIN_KEY).unwrap();
+        assert_eq!(layout, LegacyLayout::CwOwnable);
+        assert_eq!(
+            ownership,
+            Ownership {
+                owner: Some(larry),
+                pending_owner: Some(jake),
+                pending_expiry: Some(Expiration::AtHeight(100)),
+                pending_accept_after: None,
+                pending_pubkey: None,
+                pending_price: None,
+                pending_renounce: None,
+                lease_expiry: None,
+                fallback_owner: None,
+                recovery_address: None,
+                last_activity: None,
+            },
+        );
+        assert_eq!(store.get_ownership(&deps.storage, &mock_env().block).unwrap(), ownership);
+        assert!(deps.storage.get(OWNERSHIP_KEY.as_bytes()).is_none());
+    }
+
+    #[test]
+    fn ignoring_current_cw_ownable() {
+        let mut deps = mock_dependencies();
+        let larry = deps.api.addr_make("larry");
+
+        // the current ownership is stored under the same key, but isn't legacy
+        OwnershipStore::new(OWNERSHIP_KEY)
+            .initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str()))
+            .unwrap();
+        assert_eq!(detect_legacy_layout(&deps.storage, ADMIN_KEY), None);
+
+        let err = OwnershipStore::new("creator")
+            .migrate_legacy_ownership(&mut deps.storage, &deps.api, ADMIN_KEY)
+            .unwrap_err();
+        assert_eq!(err, OwnershipError::LegacyOwnershipNotFound);
+    }
+
+    #[test]
+    fn refusing_to_overwrite_ownership() {
+        let mut deps = mock_dependencies();
+        let [larry, jake] = [deps.api.addr_make("larry"), deps.api.addr_make("jake")];
+        let store = OwnershipStore::new(OWNERSHIP_KEY);
+
+        store.initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str())).unwrap();
+        Item::<Option<Addr>>::new(ADMIN_KEY).save(&mut deps.storage, &Some(jake)).unwrap();
+
+        let err =
+            store.migrate_legacy_ownership(&mut deps.storage, &deps.api, ADMIN_KEY).unwrap_err();
+        assert_eq!(err, OwnershipError::OwnershipAlreadyExists);
+
+        // the existing ownership and the legacy admin are left untouched
+        assert_eq!(
+            store.get_ownership(&deps.storage, &mock_env().block).unwrap().owner,
+            Some(larry)
+        );
+        assert!(deps.storage.get(ADMIN_KEY.as_bytes()).is_some());
+    }
+}
@@ -0,0 +1,408 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, BlockInfo, DepsMut, StdError, StdResult, Storage};
+use cw_address_like::AddressLike;
+use cw_storage_plus::Item;
+
+use crate::{Expiration, OwnershipError, OwnershipStore};
+
+/// The contract's pause info
+#[cw_serde]
+pub struct PauseInfo<T: AddressLike> {
+    /// The account who, besides the owner, may pause and unpause the contract.
+    /// `None` if only the owner may do so.
+    pub pauser: Option<T>,
+
+    /// The time at which the contract is automatically unpaused.
+    /// `None` if the contract isn't paused; `Some(Expiration::Never {})` if it
+    /// is paused until explicitly unpaused.
+    pub paused_until: Option<Expiration>,
+}
+
+impl<T: AddressLike> PauseInfo<T> {
+    /// Return true if the contract is paused at the given block.
+    pub fn is_paused(&self, block: &BlockInfo) -> bool {
+        self.paused_until.is_some_and(|until| !until.is_expired(block))
+    }
+}
+
+/// Actions that can be taken to pause or unpause the contract
+#[cw_serde]
+pub enum PauseAction {
+    /// Pause the contract, optionally until the given expiry time. If the
+    /// contract is already paused, its expiry time is overwritten.
+    ///
+    /// Can only be called by the contract's current owner or pauser.
+    Pause {
+        until: Option<Expiration>,
+    },
+
+    /// Unpause the contract.
+    ///
+    /// Can only be called by the contract's current owner or pauser.
+    Unpause,
+
+    /// Appoint an account who may pause and unpause the contract, or remove
+    /// the current one.
+    ///
+    /// Can only be called by the contract's current owner.
+    SetPauser {
+        pauser: Option<String>,
+    },
+}
+
+/// Errors associated with pausing the contract
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum PauseError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Ownership(#[from] OwnershipError),
+
+    #[error("Caller is neither the contract's current owner nor pauser")]
+    NotPauser,
+
+    #[error("Contract is paused until {0}")]
+    Paused(Expiration),
+
+    #[error("Contract is not paused")]
+    NotPaused,
+}
+
+/// Pause state controlled by the owner of an [`OwnershipStore`].
+pub struct PauseStore {
+    pub ownership: OwnershipStore,
+    pub item: Item<PauseInfo<Addr>>,
+}
+
+impl PauseStore {
+    /// Create a pause store controlled by the owner of the given ownership
+    /// store, which should be configured the same way as the store the
+    /// contract uses, e.g. by passing the same constant.
+    pub const fn new(ownership: OwnershipStore, pause_key: &'static str) -> Self {
+        Self {
+            ownership,
+            item: Item::new(pause_key),
+        }
+    }
+
+    /// Get the current pause info. The contract is considered unpaused, with
+    /// no pauser, if the pause info has never been saved.
+    pub fn get_pause_info(&self, store: &dyn Storage) -> StdResult<PauseInfo<Addr>> {
+        Ok(self.item.may_load(store)?.unwrap_or(PauseInfo {
+            pauser: None,
+            paused_until: None,
+        }))
+    }
+
+    /// Return Ok(true) if the contract is paused at the given block.
+    /// Return Err if fails to load pause info from storage.
+    pub fn is_paused(&self, store: &dyn Storage, block: &BlockInfo) -> StdResult<bool> {
+        let pause_info = self.get_pause_info(store)?;
+        Ok(pause_info.is_paused(block))
+    }
+
+    /// Assert that the contract isn't paused at the given block.
+    pub fn assert_not_paused(
+        &self,
+        store: &dyn Storage,
+        block: &BlockInfo,
+    ) -> Result<(), PauseError> {
+        let pause_info = self.get_pause_info(store)?;
+
+        if let Some(until) = pause_info.paused_until {
+            if !until.is_expired(block) {
+                return Err(PauseError::Paused(until));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Update the contract's pause info based on the given action.
+    /// Return the updated pause info.
+    pub fn update_pause(
+        &self,
+        deps: DepsMut,
+        block: &BlockInfo,
+        sender: &Addr,
+        action: PauseAction,
+    ) -> Result<PauseInfo<Addr>, PauseError> {
+        let pause_info = self.get_pause_info(deps.storage)?;
+
+        let pause_info = match action {
+            PauseAction::Pause {
+                until,
+            } => {
+                self.check_pauser(deps.storage, block, &pause_info, sender)?;
+
+                PauseInfo {
+                    paused_until: Some(until.unwrap_or_default()),
+                    ..pause_info
+                }
+            },
+            PauseAction::Unpause => {
+                self.check_pauser(deps.storage, block, &pause_info, sender)?;
+
+                // the contract must be paused
+                if !pause_info.is_paused(block) {
+                    return Err(PauseError::NotPaused);
+                }
+
+                PauseInfo {
+                    paused_until: None,
+                    ..pause_info
+                }
+            },
+            PauseAction::SetPauser {
+                pauser,
+            } => {
+                self.ownership.record_activity(deps.storage, block, sender)?;
+
+                PauseInfo {
+                    pauser: pauser.map(|pauser| deps.api.addr_validate(&pauser)).transpose()?,
+                    ..pause_info
+                }
+            },
+        };
+
+        self.item.save(deps.storage, &pause_info)?;
+
+        Ok(pause_info)
+    }
+
+    /// Assert that an account is the contract's owner at the given block, or
+    /// its pauser.
+    fn check_pauser(
+        &self,
+        store: &dyn Storage,
+        block: &BlockInfo,
+        pause_info: &PauseInfo<Addr>,
+        sender: &Addr,
+    ) -> Result<(), PauseError> {
+        if pause_info.pauser.as_ref() == Some(sender)
+            || self.ownership.is_owner(store, block, sender)?
+        {
+            return Ok(());
+        }
+
+        Err(PauseError::NotPauser)
+    }
+}
+
+//------------------------------------------------------------------------------
+// Tests
+//------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_dependencies;
+
+    use super::*;
+    use crate::{
+        testing::{mock_addresses, mock_block_at_height},
+        Activity, Duration, OWNERSHIP_KEY, PAUSE_KEY,
+    };
+
+    const PAUSE: PauseStore = PauseStore::new(OwnershipStore::new(OWNERSHIP_KEY), PAUSE_KEY);
+
+    #[test]
+    fn pausing_and_unpausing() {
+        let mut deps = mock_dependencies();
+        let [larry, jake, _] = mock_addresses(&deps.api);
+
+        PAUSE
+            .ownership
+            .initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str()))
+            .unwrap();
+
+        // contract is initially not paused
+        {
+            assert!(!PAUSE.is_paused(deps.as_ref().storage, &mock_block_at_height(12345)).unwrap());
+
+            let err = PAUSE
+                .update_pause(
+                    deps.as_mut(),
+                    &mock_block_at_height(12345),
+                    &larry,
+                    PauseAction::Unpause,
+                )
+                .unwrap_err();
+            assert_eq!(err, PauseError::NotPaused);
+        }
+
+        // non-owner cannot pause
+        {
+            let err = PAUSE
+                .update_pause(
+                    deps.as_mut(),
+                    &mock_block_at_height(12345),
+                    &jake,
+                    PauseAction::Pause {
+                        until: None,
+                    },
+                )
+                .unwrap_err();
+            assert_eq!(err, PauseError::NotPauser);
+        }
+
+        // owner properly pauses until the given expiry
+        {
+            PAUSE
+                .update_pause(
+                    deps.as_mut(),
+                    &mock_block_at_height(12345),
+                    &larry,
+                    PauseAction::Pause {
+                        until: Some(Expiration::AtHeight(20000)),
+                    },
+                )
+                .unwrap();
+
+            let err = PAUSE
+                .assert_not_paused(deps.as_ref().storage, &mock_block_at_height(19999))
+                .unwrap_err();
+            assert_eq!(err, PauseError::Paused(Expiration::AtHeight(20000)));
+
+            // contract is automatically unpaused at the expiry
+            assert!(PAUSE
+                .assert_not_paused(deps.as_ref().storage, &mock_block_at_height(20000))
+                .is_ok());
+        }
+
+        // owner properly unpauses
+        {
+            let pause_info = PAUSE
+                .update_pause(
+                    deps.as_mut(),
+                    &mock_block_at_height(12345),
+                    &larry,
+                    PauseAction::Unpause,
+                )
+                .unwrap();
+            assert_eq!(
+                pause_info,
+                PauseInfo {
+                    pauser: None,
+                    paused_until: None,
+                },
+            );
+            assert!(!PAUSE.is_paused(deps.as_ref().storage, &mock_block_at_height(12345)).unwrap());
+        }
+    }
+
+    #[test]
+    fn pausing_by_pauser() {
+        let mut deps = mock_dependencies();
+        let [larry, jake, pumpkin] = mock_addresses(&deps.api);
+
+        PAUSE
+            .ownership
+            .initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str()))
+            .unwrap();
+
+        // non-owner cannot appoint a pauser
+        {
+            let err = PAUSE
+                .update_pause(
+                    deps.as_mut(),
+                    &mock_block_at_height(12345),
+                    &jake,
+                    PauseAction::SetPauser {
+                        pauser: Some(jake.to_string()),
+                    },
+                )
+                .unwrap_err();
+            assert_eq!(err, PauseError::Ownership(OwnershipError::NotOwner));
+        }
+
+        PAUSE
+            .update_pause(
+                deps.as_mut(),
+                &mock_block_at_height(12345),
+                &larry,
+                PauseAction::SetPauser {
+                    pauser: Some(jake.to_string()),
+                },
+            )
+            .unwrap();
+
+        // pauser properly pauses indefinitely
+        {
+            let pause_info = PAUSE
+                .update_pause(
+                    deps.as_mut(),
+                    &mock_block_at_height(12345),
+                    &jake,
+                    PauseAction::Pause {
+                        until: None,
+                    },
+                )
+                .unwrap();
+            assert_eq!(
+                pause_info,
+                PauseInfo {
+                    pauser: Some(jake.clone()),
+                    paused_until: Some(Expiration::Never {}),
+                },
+            );
+        }
+
+        // accounts other than the owner and pauser cannot unpause
+        {
+            let err = PAUSE
+                .update_pause(
+                    deps.as_mut(),
+                    &mock_block_at_height(12345),
+                    &pumpkin,
+                    PauseAction::Unpause,
+                )
+                .unwrap_err();
+            assert_eq!(err, PauseError::NotPauser);
+        }
+
+        // pauser properly unpauses
+        {
+            PAUSE
+                .update_pause(
+                    deps.as_mut(),
+                    &mock_block_at_height(12345),
+                    &jake,
+                    PauseAction::Unpause,
+                )
+                .unwrap();
+            assert!(!PAUSE.is_paused(deps.as_ref().storage, &mock_block_at_height(12345)).unwrap());
+        }
+    }
+
+    #[test]
+    fn recording_owner_activity() {
+        let mut deps = mock_dependencies();
+        let [larry, jake, _] = mock_addresses(&deps.api);
+        let pause = PauseStore::new(
+            OwnershipStore::new(OWNERSHIP_KEY).with_recovery_period(Duration::Height(100)),
+            PAUSE_KEY,
+        );
+
+        pause
+            .ownership
+            .initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str()))
+            .unwrap();
+
+        // appointing a pauser counts as the owner's activity
+        let block = mock_block_at_height(12345);
+        pause
+            .update_pause(
+                deps.as_mut(),
+                &block,
+                &larry,
+                PauseAction::SetPauser {
+                    pauser: Some(jake.to_string()),
+                },
+            )
+            .unwrap();
+
+        let ownership = pause.ownership.get_ownership(deps.as_ref().storage, &block).unwrap();
+        assert_eq!(ownership.last_activity, Some(Activity::at(&block)));
+    }
+}
@@ -0,0 +1,160 @@
+use cosmwasm_std::{Addr, QuerierWrapper};
+
+use crate::OwnershipError;
+
+/// Rules restricting who may be proposed as the new owner, e.g. to prevent the
+/// ownership from being transferred to an exchange's deposit address by
+/// mistake.
+///
+/// The default policy imposes no rules, i.e. any valid address is accepted.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TransferPolicy {
+    /// Any valid address may be proposed.
+    #[default]
+    Any,
+
+    /// Only the listed addresses may be proposed.
+    Allowlist(&'static [&'static str]),
+
+    /// Only contracts may be proposed.
+    Contract,
+
+    /// Only contracts instantiated from one of the listed code ids may be
+    /// proposed.
+    ContractWithCodeId(&'static [u64]),
+}
+
+impl TransferPolicy {
+    /// Check that the given address may be proposed as the new owner, querying
+    /// its contract info if the policy requires a contract.
+    pub(crate) fn check(
+        &self,
+        querier: &QuerierWrapper,
+        new_owner: &Addr,
+    ) -> Result<(), OwnershipError> {
+        match self {
+            TransferPolicy::Any => Ok(()),
+            TransferPolicy::Allowlist(allowlist) => {
+                if !allowlist.contains(&new_owner.as_str()) {
+                    return Err(OwnershipError::NewOwnerNotAllowlisted);
+                }
+                Ok(())
+            },
+            TransferPolicy::Contract => {
+                contract_code_id(querier, new_owner)?;
+                Ok(())
+            },
+            TransferPolicy::ContractWithCodeId(code_ids) => {
+                let code_id = contract_code_id(querier, new_owner)?;
+                if !code_ids.contains(&code_id) {
+                    return Err(OwnershipError::NewOwnerCodeIdNotAllowed(code_id));
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+/// Return the code id of the given contract. Return Err if the address isn't a
+/// contract, i.e. its contract info can't be queried.
+fn contract_code_id(querier: &QuerierWrapper, addr: &Addr) -> Result<u64, OwnershipError> {
+    querier
+        .query_wasm_contract_info(addr)
+        .map(|info| info.code_id)
+        .map_err(|_| OwnershipError::NewOwnerNotContract)
+}
+
+//------------------------------------------------------------------------------
+// Tests
+//------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env},
+        to_json_binary, ContractInfoResponse, ContractResult, SystemError, SystemResult, WasmQuery,
+    };
+
+    use super::*;
+    use crate::{Action, OwnershipStore, OWNERSHIP_KEY};
+
+    fn transfer(new_owner: &Addr) -> Action {
+        Action::TransferOwnership {
+            new_owner: new_owner.to_string(),
+            expiry: None,
+            expires_in: None,
+            delay: None,
+            pubkey: None,
+            price: None,
+        }
+    }
+
+    #[test]
+    fn enforcing_transfer_policy() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let larry = deps.api.addr_make("larry");
+        let exchange = deps.api.addr_make("exchange");
+        let dao = deps.api.addr_make("dao");
+        let vault = deps.api.addr_make("vault");
+
+        // the dao is instantiated from code id 1 and the vault from code id 2,
+        // while any other address isn't a contract
+        let contracts = [(dao.clone(), 1), (vault.clone(), 2)];
+        deps.querier.update_wasm(move |query| match query {
+            WasmQuery::ContractInfo {
+                contract_addr,
+            } => match contracts.iter().find(|(addr, _)| addr.as_str() == contract_addr) {
+                Some((_, code_id)) => {
+                    let info = ContractInfoResponse::new(
+                        *code_id,
+                        Addr::unchecked("creator"),
+                        None,
+                        false,
+                        None,
+                    );
+                    SystemResult::Ok(ContractResult::Ok(to_json_binary(&info).unwrap()))
+                },
+                None => SystemResult::Err(SystemError::NoSuchContract {
+                    addr: contract_addr.clone(),
+                }),
+            },
+            _ => unimplemented!(),
+        });
+
+        let mut check = |policy: TransferPolicy, new_owner: &Addr| {
+            let store = OwnershipStore::new(OWNERSHIP_KEY).with_transfer_policy(policy);
+            store.initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str())).unwrap();
+            store
+                .update_ownership(deps.as_mut(), &env.block, &larry, transfer(new_owner))
+                .map(|_| ())
+        };
+
+        // the default policy accepts any address
+        assert_eq!(check(TransferPolicy::Any, &exchange), Ok(()));
+
+        // contracts would declare the allowlist as a constant
+        let allowlist: &'static [&'static str] = vec![&*dao.to_string().leak()].leak();
+        assert_eq!(
+            check(TransferPolicy::Allowlist(allowlist), &exchange),
+            Err(OwnershipError::NewOwnerNotAllowlisted),
+        );
+        assert_eq!(check(TransferPolicy::Allowlist(allowlist), &dao), Ok(()));
+
+        assert_eq!(
+            check(TransferPolicy::Contract, &exchange),
+            Err(OwnershipError::NewOwnerNotContract),
+        );
+        assert_eq!(check(TransferPolicy::Contract, &vault), Ok(()));
+
+        assert_eq!(
+            check(TransferPolicy::ContractWithCodeId(&[1]), &exchange),
+            Err(OwnershipError::NewOwnerNotContract),
+        );
+        assert_eq!(
+            check(TransferPolicy::ContractWithCodeId(&[1]), &vault),
+            Err(OwnershipError::NewOwnerCodeIdNotAllowed(2)),
+        );
+        assert_eq!(check(TransferPolicy::ContractWithCodeId(&[1]), &dao), Ok(()));
+    }
+}
@@ -0,0 +1,212 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{to_json_binary, Addr, Binary, BlockInfo, Coin, Deps, Env, StdResult, Storage};
+
+use crate::{Expiration, OwnershipStore};
+
+/// Queries that can be made about the contract's ownership.
+///
+/// The variants are named and shaped the same as those inserted by the
+/// `cw_ownable_query` macro, so that they can be handled by
+/// [`OwnershipStore::query_ownership`].
+#[cw_serde]
+pub enum OwnershipQuery {
+    /// Query the contract's ownership information, including its lease, if
+    /// any. If the lease has ended, the fallback owner is shown as the owner.
+    Ownership {},
+
+    /// Query whether the given address is the contract's current owner,
+    /// taking into account whether the lease has ended
+    IsOwner {
+        address: String,
+    },
+
+    /// Query the pending ownership transfer, and whether it can currently be
+    /// accepted
+    PendingOwnership {},
+
+    /// Query the changes made to the contract's ownership, in the order they
+    /// were made
+    OwnershipHistory {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+
+    /// Query the status of the ownership's recovery, and whether the recovery
+    /// address can currently claim the ownership
+    OwnershipRecoveryStatus {},
+}
+
+/// The status of the pending ownership transfer at a given block
+#[cw_serde]
+pub struct PendingOwnership {
+    /// The account who has been proposed to take over the ownership.
+    /// `None` if there isn't a pending ownership transfer.
+    pub pending_owner: Option<Addr>,
+
+    /// The deadline for the pending owner to accept the ownership, if any.
+    pub expiry: Option<Expiration>,
+
+    /// The earliest time at which the pending owner may accept the ownership,
+    /// if any.
+    pub accept_after: Option<Expiration>,
+
+    /// The funds the pending owner must send to accept the ownership, if any.
+    pub price: Option<Vec<Coin>>,
+
+    /// Whether the transfer's deadline has been reached.
+    pub expired: bool,
+
+    /// Whether the pending owner can accept the ownership at the given block,
+    /// i.e. there is a pending transfer, it hasn't expired, and its delay, if
+    /// any, has elapsed.
+    pub acceptable: bool,
+}
+
+impl OwnershipStore {
+    /// Return the status of the pending ownership transfer at the given block.
+    pub fn get_pending_ownership(
+        &self,
+        storage: &dyn Storage,
+        block: &BlockInfo,
+    ) -> StdResult<PendingOwnership> {
+        let ownership = self.get_ownership(storage, block)?;
+
+        let expired = ownership.pending_expiry.is_some_and(|expiry| expiry.is_expired(block));
+        let delay_elapsed =
+            ownership.pending_accept_after.is_none_or(|after| after.is_expired(block));
+
+        Ok(PendingOwnership {
+            acceptable: ownership.pending_owner.is_some() && !expired && delay_elapsed,
+            pending_owner: ownership.pending_owner,
+            expiry: ownership.pending_expiry,
+            accept_after: ownership.pending_accept_after,
+            price: ownership.pending_price,
+            expired,
+        })
+    }
+
+    /// Handle a query about the ownership, serializing the response.
+    pub fn query_ownership(
+        &self,
+        deps: Deps,
+        env: &Env,
+        query: OwnershipQuery,
+    ) -> StdResult<Binary> {
+        match query {
+            OwnershipQuery::Ownership {} => {
+                to_json_binary(&self.get_ownership(deps.storage, &env.block)?)
+            },
+            OwnershipQuery::IsOwner {
+                address,
+            } => {
+                let address = deps.api.addr_validate(&address)?;
+                to_json_binary(&self.is_owner(deps.storage, &env.block, &address)?)
+            },
+            OwnershipQuery::PendingOwnership {} => {
+                to_json_binary(&self.get_pending_ownership(deps.storage, &env.block)?)
+            },
+            OwnershipQuery::OwnershipHistory {
+                start_after,
+                limit,
+            } => to_json_binary(&self.query_history(deps.storage, start_after, limit)?),
+            OwnershipQuery::OwnershipRecoveryStatus {} => {
+                to_json_binary(&self.get_recovery_status(deps.storage, &env.block)?)
+            },
+        }
+    }
+}
+
+//------------------------------------------------------------------------------
+// Tests
+//------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{
+        from_json,
+        testing::{mock_dependencies, mock_env},
+    };
+
+    use super::*;
+    use crate::{Ownership, OWNERSHIP_KEY};
+
+    const OWNERSHIP: OwnershipStore = OwnershipStore::new(OWNERSHIP_KEY);
+
+    #[test]
+    fn querying_ownership() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let larry = deps.api.addr_make("larry");
+        let jake = deps.api.addr_make("jake");
+
+        let ownership = Ownership {
+            owner: Some(larry.clone()),
+            pending_owner: Some(jake.clone()),
+            pending_expiry: Some(Expiration::AtHeight(env.block.height + 100)),
+            pending_accept_after: Some(Expiration::AtHeight(env.block.height + 10)),
+            pending_pubkey: None,
+            pending_price: None,
+            pending_renounce: None,
+            lease_expiry: None,
+            fallback_owner: None,
+            recovery_address: None,
+            last_activity: None,
+        };
+        OWNERSHIP.item.save(deps.as_mut().storage, &ownership).unwrap();
+
+        let query = |deps: Deps, env: &Env, query| OWNERSHIP.query_ownership(deps, env, query);
+
+        let res = query(deps.as_ref(), &env, OwnershipQuery::Ownership {}).unwrap();
+        assert_eq!(from_json::<Ownership<Addr>>(res).unwrap(), ownership);
+
+        for (address, expected) in [(&larry, true), (&jake, false)] {
+            let res = query(
+                deps.as_ref(),
+                &env,
+                OwnershipQuery::IsOwner {
+                    address: address.to_string(),
+                },
+            )
+            .unwrap();
+            assert_eq!(from_json::<bool>(res).unwrap(), expected);
+        }
+
+        // the transfer's delay hasn't elapsed
+        let res = query(deps.as_ref(), &env, OwnershipQuery::PendingOwnership {}).unwrap();
+        assert_eq!(
+            from_json::<PendingOwnership>(res).unwrap(),
+            PendingOwnership {
+                pending_owner: Some(jake.clone()),
+                expiry: ownership.pending_expiry,
+                accept_after: ownership.pending_accept_after,
+                price: None,
+                expired: false,
+                acceptable: false,
+            },
+        );
+
+        // the transfer can be accepted
+        env.block.height += 10;
+        let pending = OWNERSHIP.get_pending_ownership(deps.as_ref().storage, &env.block).unwrap();
+        assert!(!pending.expired);
+        assert!(pending.acceptable);
+
+        // the transfer has expired
+        env.block.height += 90;
+        let pending = OWNERSHIP.get_pending_ownership(deps.as_ref().storage, &env.block).unwrap();
+        assert!(pending.expired);
+        assert!(!pending.acceptable);
+
+        // history isn't recorded by this store
+        let err = query(
+            deps.as_ref(),
+            &env,
+            OwnershipQuery::OwnershipHistory {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("ownership history is not recorded"));
+    }
+}
@@ -0,0 +1,308 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, BlockInfo, StdResult, Storage, Timestamp};
+
+use crate::{check_owner, Duration, Expiration, OwnershipError, OwnershipStore};
+
+/// The block at which the owner last acted on the ownership
+#[cw_serde]
+#[derive(Copy)]
+pub struct Activity {
+    pub height: u64,
+    pub time: Timestamp,
+}
+
+impl Activity {
+    pub fn at(block: &BlockInfo) -> Self {
+        Self {
+            height: block.height,
+            time: block.time,
+        }
+    }
+
+    /// Return the time at which the given period of inactivity, counted from
+    /// this activity, has passed.
+    pub fn plus(&self, period: Duration) -> Expiration {
+        match period {
+            Duration::Height(blocks) => Expiration::AtHeight(self.height + blocks),
+            Duration::Time(seconds) => Expiration::AtTime(self.time.plus_seconds(seconds)),
+        }
+    }
+}
+
+/// The status of the ownership's recovery at a given block
+#[cw_serde]
+pub struct RecoveryStatus {
+    /// The account who may claim the ownership once the owner has been
+    /// inactive for long enough. `None` if a recovery address hasn't been set.
+    pub recovery_address: Option<Addr>,
+
+    /// The block at which the owner last acted on the ownership, if any.
+    pub last_activity: Option<Activity>,
+
+    /// The time at which the recovery address may claim the ownership, unless
+    /// the owner acts again in the meantime.
+    /// `None` if the ownership can't be recovered.
+    pub recoverable_at: Option<Expiration>,
+
+    /// How long remains before the recovery address may claim the ownership.
+    /// Zero if it may already do so, and `None` if the ownership can't be
+    /// recovered.
+    pub remaining: Option<Duration>,
+
+    /// Whether the recovery address may claim the ownership at the given
+    /// block.
+    pub claimable: bool,
+}
+
+impl OwnershipStore {
+    /// Return the status of the ownership's recovery at the given block.
+    pub fn get_recovery_status(
+        &self,
+        storage: &dyn Storage,
+        block: &BlockInfo,
+    ) -> StdResult<RecoveryStatus> {
+        let ownership = self.get_ownership(storage, block)?;
+
+        let recoverable_at = match (self.recovery_period, &ownership.recovery_address) {
+            (Some(period), Some(_)) => {
+                ownership.last_activity.map(|activity| activity.plus(period))
+            },
+            _ => None,
+        };
+        let remaining = recoverable_at.map(|at| remaining_until(at, block));
+
+        Ok(RecoveryStatus {
+            claimable: recoverable_at.is_some_and(|at| at.is_expired(block)),
+            recovery_address: ownership.recovery_address,
+            last_activity: ownership.last_activity,
+            recoverable_at,
+            remaining,
+        })
+    }
+
+    /// Assert that an account is the contract's owner at the given block, and
+    /// record the block as the owner's last activity.
+    ///
+    /// Ownership actions record the owner's activity themselves, but the
+    /// owner's other actions don't, so a contract with a recovery period should
+    /// call this in its owner-only handlers. Otherwise an owner that keeps
+    /// using the contract may still be considered inactive, and lose the
+    /// ownership to the recovery address. `#[only_owner]` calls it for
+    /// handlers taking a `DepsMut`, as do `RoleStore` and `PauseStore` for the
+    /// owner's actions.
+    ///
+    /// Without a recovery period the activity isn't used, so nothing is saved.
+    pub fn record_activity(
+        &self,
+        store: &mut dyn Storage,
+        block: &BlockInfo,
+        sender: &Addr,
+    ) -> Result<(), OwnershipError> {
+        let mut ownership = self.get_ownership(store, block)?;
+        check_owner(&ownership, sender)?;
+
+        if self.recovery_period.is_some() {
+            ownership.last_activity = Some(Activity::at(block));
+            self.item.save(store, &ownership)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn remaining_until(expiration: Expiration, block: &BlockInfo) -> Duration {
+    match expiration {
+        Expiration::AtHeight(height) => Duration::Height(height.saturating_sub(block.height)),
+        Expiration::AtTime(time) => {
+            Duration::Time(time.seconds().saturating_sub(block.time.seconds()))
+        },
+        Expiration::Never {} => unreachable!("recovery always has an expiration"),
+    }
+}
+
+//------------------------------------------------------------------------------
+// Tests
+//------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+
+    use super::*;
+    use crate::{Action, Ownership, OwnershipError, OWNERSHIP_KEY};
+
+    const OWNERSHIP: OwnershipStore =
+        OwnershipStore::new(OWNERSHIP_KEY).with_recovery_period(Duration::Time(1000));
+
+    #[test]
+    fn recovering_ownership() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let larry = deps.api.addr_make("larry");
+        let jake = deps.api.addr_make("jake");
+        let recovery = deps.api.addr_make("recovery");
+
+        OWNERSHIP.initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str())).unwrap();
+
+        // only the owner can set the recovery address
+        {
+            let err = OWNERSHIP
+                .update_ownership(
+                    deps.as_mut(),
+                    &env.block,
+                    &jake,
+                    Action::SetRecoveryAddress {
+                        address: Some(recovery.to_string()),
+                    },
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::NotOwner);
+
+            let ownership = OWNERSHIP
+                .update_ownership(
+                    deps.as_mut(),
+                    &env.block,
+                    &larry,
+                    Action::SetRecoveryAddress {
+                        address: Some(recovery.to_string()),
+                    },
+                )
+                .unwrap();
+            assert_eq!(ownership.recovery_address, Some(recovery.clone()));
+            assert_eq!(ownership.last_activity, Some(Activity::at(&env.block)));
+        }
+
+        // the recovery address can't claim while the owner is active
+        {
+            env.block.time = env.block.time.plus_seconds(600);
+
+            let status = OWNERSHIP.get_recovery_status(deps.as_ref().storage, &env.block).unwrap();
+            assert_eq!(status.remaining, Some(Duration::Time(400)));
+            assert!(!status.claimable);
+
+            let err = OWNERSHIP
+                .update_ownership(deps.as_mut(), &env.block, &recovery, Action::ClaimRecovery)
+                .unwrap_err();
+            assert_eq!(
+                err,
+                OwnershipError::RecoveryNotYetClaimable(status.recoverable_at.unwrap())
+            );
+
+            // a heartbeat restarts the inactivity period
+            OWNERSHIP
+                .update_ownership(deps.as_mut(), &env.block, &larry, Action::Heartbeat)
+                .unwrap();
+
+            env.block.time = env.block.time.plus_seconds(600);
+            let status = OWNERSHIP.get_recovery_status(deps.as_ref().storage, &env.block).unwrap();
+            assert_eq!(status.remaining, Some(Duration::Time(400)));
+        }
+
+        // only the recovery address can claim after the inactivity period
+        {
+            env.block.time = env.block.time.plus_seconds(400);
+
+            let status = OWNERSHIP.get_recovery_status(deps.as_ref().storage, &env.block).unwrap();
+            assert_eq!(status.remaining, Some(Duration::Time(0)));
+            assert!(status.claimable);
+
+            let err = OWNERSHIP
+                .update_ownership(deps.as_mut(), &env.block, &jake, Action::ClaimRecovery)
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::NotRecoveryAddress);
+
+            let ownership = OWNERSHIP
+                .update_ownership(deps.as_mut(), &env.block, &recovery, Action::ClaimRecovery)
+                .unwrap();
+            assert_eq!(ownership.owner, Some(recovery));
+            assert_eq!(ownership.recovery_address, None);
+
+            let status = OWNERSHIP.get_recovery_status(deps.as_ref().storage, &env.block).unwrap();
+            assert_eq!(status.recoverable_at, None);
+            assert!(!status.claimable);
+        }
+    }
+
+    #[test]
+    fn initializing_recoverable_ownership() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let larry = deps.api.addr_make("larry");
+        let recovery = deps.api.addr_make("recovery");
+
+        // a back-dated activity is ignored, so the ownership can't be claimed
+        // right away
+        let ownership = OWNERSHIP
+            .initialize_ownership(
+                &mut deps.storage,
+                &deps.api,
+                &env.block,
+                Ownership {
+                    recovery_address: Some(recovery.to_string()),
+                    last_activity: Some(Activity {
+                        height: 0,
+                        time: Timestamp::from_seconds(0),
+                    }),
+                    ..Ownership::new(Some(larry.to_string()))
+                },
+            )
+            .unwrap();
+        assert_eq!(ownership.recovery_address, Some(recovery.clone()));
+        assert_eq!(ownership.last_activity, Some(Activity::at(&env.block)));
+
+        let err = OWNERSHIP
+            .update_ownership(deps.as_mut(), &env.block, &recovery, Action::ClaimRecovery)
+            .unwrap_err();
+        assert!(matches!(err, OwnershipError::RecoveryNotYetClaimable(_)));
+    }
+
+    #[test]
+    fn recording_owner_activity() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let larry = deps.api.addr_make("larry");
+        let jake = deps.api.addr_make("jake");
+
+        OWNERSHIP.initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str())).unwrap();
+
+        // only the owner's activity is recorded
+        let err = OWNERSHIP.record_activity(&mut deps.storage, &env.block, &jake).unwrap_err();
+        assert_eq!(err, OwnershipError::NotOwner);
+
+        env.block.time = env.block.time.plus_seconds(600);
+        OWNERSHIP.record_activity(&mut deps.storage, &env.block, &larry).unwrap();
+
+        let status = OWNERSHIP.get_recovery_status(deps.as_ref().storage, &env.block).unwrap();
+        assert_eq!(status.last_activity, Some(Activity::at(&env.block)));
+
+        // without a recovery period, the activity isn't saved
+        let store = OwnershipStore::new("other_ownership");
+        store.initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str())).unwrap();
+        store.record_activity(&mut deps.storage, &env.block, &larry).unwrap();
+
+        let ownership = store.get_ownership(deps.as_ref().storage, &env.block).unwrap();
+        assert_eq!(ownership.last_activity, None);
+    }
+
+    #[test]
+    fn recovery_requires_a_period() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let larry = deps.api.addr_make("larry");
+
+        let store = OwnershipStore::new(OWNERSHIP_KEY);
+        store.initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str())).unwrap();
+
+        let err = store
+            .update_ownership(
+                deps.as_mut(),
+                &env.block,
+                &larry,
+                Action::SetRecoveryAddress {
+                    address: Some(larry.to_string()),
+                },
+            )
+            .unwrap_err();
+        assert_eq!(err, OwnershipError::RecoveryDisabled);
+    }
+}
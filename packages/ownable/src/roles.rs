@@ -0,0 +1,476 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, BlockInfo, DepsMut, Empty, StdError, StdResult, Storage};
+use cw_storage_plus::{Bound, Map};
+
+use crate::{OwnershipError, OwnershipStore};
+
+/// Actions that can be taken to alter the contract's role assignments
+#[cw_serde]
+pub enum RoleAction {
+    /// Grant a role to an account.
+    ///
+    /// Can only be called by the contract's current owner.
+    GrantRole {
+        role: String,
+        account: String,
+    },
+
+    /// Revoke a role from an account.
+    ///
+    /// Can only be called by the contract's current owner.
+    RevokeRole {
+        role: String,
+        account: String,
+    },
+
+    /// Give up a role held by the caller.
+    ///
+    /// Can only be called by an account that currently holds the role.
+    RenounceRole {
+        role: String,
+    },
+}
+
+/// Errors associated with the contract's roles
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum RoleError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Ownership(#[from] OwnershipError),
+
+    #[error("Role name cannot be empty")]
+    EmptyRole,
+
+    #[error("Account {account} does not have role `{role}`")]
+    MissingRole {
+        role: String,
+        account: String,
+    },
+
+    #[error("Account {account} already has role `{role}`")]
+    AlreadyHasRole {
+        role: String,
+        account: String,
+    },
+}
+
+/// Named roles granted by the owner of an [`OwnershipStore`].
+pub struct RoleStore {
+    pub ownership: OwnershipStore,
+    pub members: Map<(String, Addr), Empty>,
+}
+
+impl RoleStore {
+    /// Create a role store whose roles are granted by the owner of the given
+    /// ownership store, which should be configured the same way as the store
+    /// the contract uses, e.g. by passing the same constant.
+    pub const fn new(ownership: OwnershipStore, roles_key: &'static str) -> Self {
+        Self {
+            ownership,
+            members: Map::new(roles_key),
+        }
+    }
+
+    /// Return Ok(true) if the given address has been granted the role.
+    /// Return Err if fails to load role info from storage.
+    pub fn has_role(&self, store: &dyn Storage, role: &str, addr: &Addr) -> StdResult<bool> {
+        Ok(self.members.has(store, (role.to_string(), addr.clone())))
+    }
+
+    /// Assert that an account has been granted the role.
+    pub fn assert_role(
+        &self,
+        store: &dyn Storage,
+        role: &str,
+        sender: &Addr,
+    ) -> Result<(), RoleError> {
+        if !self.has_role(store, role, sender)? {
+            return Err(RoleError::MissingRole {
+                role: role.to_string(),
+                account: sender.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Update the contract's role assignments based on the given action.
+    ///
+    /// Roles are granted and revoked by the owner at the given block, i.e. by
+    /// the fallback owner once the ownership's lease has ended, which records
+    /// the owner's activity.
+    pub fn update_roles(
+        &self,
+        deps: DepsMut,
+        block: &BlockInfo,
+        sender: &Addr,
+        action: RoleAction,
+    ) -> Result<(), RoleError> {
+        match action {
+            RoleAction::GrantRole {
+                role,
+                account,
+            } => {
+                self.ownership.record_activity(deps.storage, block, sender)?;
+                let account = deps.api.addr_validate(&account)?;
+                self.grant_role(deps.storage, role, account)
+            },
+            RoleAction::RevokeRole {
+                role,
+                account,
+            } => {
+                self.ownership.record_activity(deps.storage, block, sender)?;
+                let account = deps.api.addr_validate(&account)?;
+                self.revoke_role(deps.storage, role, account)
+            },
+            RoleAction::RenounceRole {
+                role,
+            } => self.revoke_role(deps.storage, role, sender.clone()),
+        }
+    }
+
+    /// Return the accounts that have been granted the role, ordered by address.
+    pub fn query_role_members(
+        &self,
+        store: &dyn Storage,
+        role: &str,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<Addr>> {
+        let start = start_after.map(|addr| Bound::exclusive(Addr::unchecked(addr)));
+        cw_paginate::paginate_map_prefix(
+            &self.members,
+            store,
+            role.to_string(),
+            start,
+            limit,
+            |addr, _| Ok(addr),
+        )
+    }
+
+    fn grant_role(
+        &self,
+        store: &mut dyn Storage,
+        role: String,
+        account: Addr,
+    ) -> Result<(), RoleError> {
+        if role.is_empty() {
+            return Err(RoleError::EmptyRole);
+        }
+
+        if self.has_role(store, &role, &account)? {
+            return Err(RoleError::AlreadyHasRole {
+                role,
+                account: account.into(),
+            });
+        }
+
+        self.members.save(store, (role, account), &Empty {})?;
+
+        Ok(())
+    }
+
+    fn revoke_role(
+        &self,
+        store: &mut dyn Storage,
+        role: String,
+        account: Addr,
+    ) -> Result<(), RoleError> {
+        if !self.has_role(store, &role, &account)? {
+            return Err(RoleError::MissingRole {
+                role,
+                account: account.into(),
+            });
+        }
+
+        self.members.remove(store, (role, account));
+
+        Ok(())
+    }
+}
+
+//------------------------------------------------------------------------------
+// Tests
+//------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+
+    use super::*;
+    use crate::{
+        testing::{mock_addresses, mock_block_at_height},
+        Action, Activity, Duration, Expiration, OWNERSHIP_KEY, ROLES_KEY,
+    };
+
+    const ROLES: RoleStore = RoleStore::new(OwnershipStore::new(OWNERSHIP_KEY), ROLES_KEY);
+
+    #[test]
+    fn granting_roles() {
+        let mut deps = mock_dependencies();
+        let [larry, jake, pumpkin] = mock_addresses(&deps.api);
+
+        ROLES
+            .ownership
+            .initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str()))
+            .unwrap();
+
+        // non-owner cannot grant roles
+        {
+            let err = ROLES
+                .update_roles(
+                    deps.as_mut(),
+                    &mock_env().block,
+                    &jake,
+                    RoleAction::GrantRole {
+                        role: "pauser".into(),
+                        account: pumpkin.to_string(),
+                    },
+                )
+                .unwrap_err();
+            assert_eq!(err, RoleError::Ownership(OwnershipError::NotOwner));
+        }
+
+        // role name must not be empty
+        {
+            let err = ROLES
+                .update_roles(
+                    deps.as_mut(),
+                    &mock_env().block,
+                    &larry,
+                    RoleAction::GrantRole {
+                        role: "".into(),
+                        account: pumpkin.to_string(),
+                    },
+                )
+                .unwrap_err();
+            assert_eq!(err, RoleError::EmptyRole);
+        }
+
+        // owner properly grants roles
+        {
+            for account in [&jake, &pumpkin] {
+                ROLES
+                    .update_roles(
+                        deps.as_mut(),
+                        &mock_env().block,
+                        &larry,
+                        RoleAction::GrantRole {
+                            role: "pauser".into(),
+                            account: account.to_string(),
+                        },
+                    )
+                    .unwrap();
+            }
+
+            assert!(ROLES.has_role(deps.as_ref().storage, "pauser", &pumpkin).unwrap());
+            assert!(ROLES.assert_role(deps.as_ref().storage, "pauser", &jake).is_ok());
+            assert!(!ROLES.has_role(deps.as_ref().storage, "operator", &pumpkin).unwrap());
+            assert!(!ROLES.has_role(deps.as_ref().storage, "pauser", &larry).unwrap());
+        }
+
+        // cannot grant the same role twice
+        {
+            let err = ROLES
+                .update_roles(
+                    deps.as_mut(),
+                    &mock_env().block,
+                    &larry,
+                    RoleAction::GrantRole {
+                        role: "pauser".into(),
+                        account: pumpkin.to_string(),
+                    },
+                )
+                .unwrap_err();
+            assert_eq!(
+                err,
+                RoleError::AlreadyHasRole {
+                    role: "pauser".into(),
+                    account: pumpkin.to_string(),
+                },
+            );
+        }
+
+        // once the owner's lease has ended, the fallback owner grants roles
+        {
+            let mut block = mock_env().block;
+            ROLES
+                .ownership
+                .update_ownership(
+                    deps.as_mut(),
+                    &block,
+                    &larry,
+                    Action::SetLease {
+                        expiry: Expiration::AtHeight(block.height + 1),
+                        fallback_owner: jake.to_string(),
+                    },
+                )
+                .unwrap();
+            block.height += 1;
+
+            let grant = RoleAction::GrantRole {
+                role: "operator".into(),
+                account: pumpkin.to_string(),
+            };
+            let err = ROLES.update_roles(deps.as_mut(), &block, &larry, grant.clone()).unwrap_err();
+            assert_eq!(err, RoleError::Ownership(OwnershipError::NotOwner));
+
+            ROLES.update_roles(deps.as_mut(), &block, &jake, grant).unwrap();
+            assert!(ROLES.has_role(deps.as_ref().storage, "operator", &pumpkin).unwrap());
+        }
+    }
+
+    #[test]
+    fn revoking_and_renouncing_roles() {
+        let mut deps = mock_dependencies();
+        let [larry, jake, pumpkin] = mock_addresses(&deps.api);
+
+        ROLES
+            .ownership
+            .initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str()))
+            .unwrap();
+        ROLES.grant_role(deps.as_mut().storage, "pauser".into(), jake.clone()).unwrap();
+        ROLES.grant_role(deps.as_mut().storage, "pauser".into(), pumpkin.clone()).unwrap();
+
+        // non-owner cannot revoke roles
+        {
+            let err = ROLES
+                .update_roles(
+                    deps.as_mut(),
+                    &mock_env().block,
+                    &jake,
+                    RoleAction::RevokeRole {
+                        role: "pauser".into(),
+                        account: pumpkin.to_string(),
+                    },
+                )
+                .unwrap_err();
+            assert_eq!(err, RoleError::Ownership(OwnershipError::NotOwner));
+        }
+
+        // owner properly revokes a role
+        {
+            ROLES
+                .update_roles(
+                    deps.as_mut(),
+                    &mock_env().block,
+                    &larry,
+                    RoleAction::RevokeRole {
+                        role: "pauser".into(),
+                        account: pumpkin.to_string(),
+                    },
+                )
+                .unwrap();
+
+            let err = ROLES.assert_role(deps.as_ref().storage, "pauser", &pumpkin).unwrap_err();
+            assert_eq!(
+                err,
+                RoleError::MissingRole {
+                    role: "pauser".into(),
+                    account: pumpkin.to_string(),
+                },
+            );
+        }
+
+        // member properly renounces its role
+        {
+            ROLES
+                .update_roles(
+                    deps.as_mut(),
+                    &mock_env().block,
+                    &jake,
+                    RoleAction::RenounceRole {
+                        role: "pauser".into(),
+                    },
+                )
+                .unwrap();
+
+            assert!(!ROLES.has_role(deps.as_ref().storage, "pauser", &jake).unwrap());
+        }
+
+        // cannot renounce a role that isn't held
+        {
+            let err = ROLES
+                .update_roles(
+                    deps.as_mut(),
+                    &mock_env().block,
+                    &jake,
+                    RoleAction::RenounceRole {
+                        role: "pauser".into(),
+                    },
+                )
+                .unwrap_err();
+            assert_eq!(
+                err,
+                RoleError::MissingRole {
+                    role: "pauser".into(),
+                    account: jake.to_string(),
+                },
+            );
+        }
+    }
+
+    #[test]
+    fn querying_role_members() {
+        let mut deps = mock_dependencies();
+        let mut addrs = mock_addresses(&deps.api);
+        addrs.sort();
+
+        for addr in &addrs {
+            ROLES.grant_role(deps.as_mut().storage, "operator".into(), addr.clone()).unwrap();
+        }
+        ROLES.grant_role(deps.as_mut().storage, "pauser".into(), addrs[0].clone()).unwrap();
+
+        let members =
+            ROLES.query_role_members(deps.as_ref().storage, "operator", None, None).unwrap();
+        assert_eq!(members, addrs.to_vec());
+
+        let members = ROLES
+            .query_role_members(
+                deps.as_ref().storage,
+                "operator",
+                Some(addrs[0].to_string()),
+                Some(1),
+            )
+            .unwrap();
+        assert_eq!(members, vec![addrs[1].clone()]);
+
+        let members =
+            ROLES.query_role_members(deps.as_ref().storage, "pauser", None, None).unwrap();
+        assert_eq!(members, vec![addrs[0].clone()]);
+    }
+
+    #[test]
+    fn recording_owner_activity() {
+        let mut deps = mock_dependencies();
+        let [larry, jake, _] = mock_addresses(&deps.api);
+        let roles = RoleStore::new(
+            OwnershipStore::new(OWNERSHIP_KEY).with_recovery_period(Duration::Height(100)),
+            ROLES_KEY,
+        );
+
+        roles
+            .ownership
+            .initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str()))
+            .unwrap();
+
+        // granting a role counts as the owner's activity
+        let block = mock_block_at_height(12345);
+        roles
+            .update_roles(
+                deps.as_mut(),
+                &block,
+                &larry,
+                RoleAction::GrantRole {
+                    role: "minter".into(),
+                    account: jake.to_string(),
+                },
+            )
+            .unwrap();
+
+        let ownership = roles.ownership.get_ownership(deps.as_ref().storage, &block).unwrap();
+        assert_eq!(ownership.last_activity, Some(Activity::at(&block)));
+    }
+}
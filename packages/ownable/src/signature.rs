@@ -0,0 +1,444 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{to_json_vec, Addr, Api, Binary, CanonicalAddr, DepsMut, Empty, Env, StdResult};
+use cw_storage_plus::Map;
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+
+use crate::{OwnershipError, OwnershipStore};
+
+/// Separates the payload of a signed acceptance from any other message signed
+/// by the same key.
+pub const ACCEPTANCE_DOMAIN: &str = "cw-ownable/accept-ownership";
+
+/// The public key of a pending owner, with which it can accept the ownership
+/// by signing off-chain
+#[cw_serde]
+pub enum PublicKey {
+    /// A compressed or uncompressed secp256k1 public key
+    Secp256k1(Binary),
+
+    /// An ed25519 public key
+    Ed25519(Binary),
+}
+
+impl PublicKey {
+    /// Return the address of the account controlled by the key, derived the
+    /// way Cosmos SDK chains do: the RIPEMD-160 hash of the SHA-256 hash of the
+    /// compressed secp256k1 key, or the first 20 bytes of the SHA-256 hash of
+    /// the ed25519 key.
+    pub fn canonical_address(&self) -> Result<CanonicalAddr, OwnershipError> {
+        match self {
+            PublicKey::Secp256k1(pubkey) => {
+                let compressed = match pubkey.as_slice() {
+                    [0x02 | 0x03, ..] if pubkey.len() == 33 => pubkey.to_vec(),
+                    [0x04, point @ ..] if pubkey.len() == 65 => {
+                        // the prefix encodes the parity of the y coordinate
+                        let prefix = 0x02 | (point[63] & 1);
+                        [&[prefix], &point[..32]].concat()
+                    },
+                    _ => return Err(OwnershipError::InvalidPubkey),
+                };
+                Ok(Ripemd160::digest(Sha256::digest(compressed)).to_vec().into())
+            },
+            PublicKey::Ed25519(pubkey) => {
+                if pubkey.len() != 32 {
+                    return Err(OwnershipError::InvalidPubkey);
+                }
+                Ok(Sha256::digest(pubkey)[..20].to_vec().into())
+            },
+        }
+    }
+
+    /// Assert that the key controls the given account, so that an owner can't
+    /// accept a transfer on the pending owner's behalf with a key of its own.
+    pub(crate) fn check_controls(&self, api: &dyn Api, addr: &Addr) -> Result<(), OwnershipError> {
+        if api.addr_canonicalize(addr.as_str())? != self.canonical_address()? {
+            return Err(OwnershipError::PubkeyMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// Verify the key's signature of the given message hash.
+    pub fn verify(
+        &self,
+        api: &dyn Api,
+        message_hash: &[u8],
+        signature: &[u8],
+    ) -> Result<(), OwnershipError> {
+        let valid = match self {
+            PublicKey::Secp256k1(pubkey) => api.secp256k1_verify(message_hash, signature, pubkey),
+            PublicKey::Ed25519(pubkey) => api.ed25519_verify(message_hash, signature, pubkey),
+        };
+
+        // a malformed key or signature is as invalid as a wrong signature
+        if !valid.unwrap_or(false) {
+            return Err(OwnershipError::InvalidSignature);
+        }
+
+        Ok(())
+    }
+}
+
+/// The payload that a pending owner signs to accept the ownership.
+///
+/// The signature is made over the SHA-256 hash of the payload serialized as
+/// JSON, with the fields in the order they're declared in.
+#[cw_serde]
+pub struct AcceptancePayload {
+    /// Always [`ACCEPTANCE_DOMAIN`]
+    pub domain: String,
+
+    /// The contract whose ownership is accepted
+    pub contract: Addr,
+
+    /// The chain on which the contract is deployed
+    pub chain_id: String,
+
+    /// The pending owner who accepts the ownership
+    pub pending_owner: Addr,
+
+    /// A number chosen by the pending owner, which can only be used once
+    pub nonce: u64,
+}
+
+impl AcceptancePayload {
+    pub fn new(contract: &Addr, chain_id: &str, pending_owner: &Addr, nonce: u64) -> Self {
+        Self {
+            domain: ACCEPTANCE_DOMAIN.into(),
+            contract: contract.clone(),
+            chain_id: chain_id.into(),
+            pending_owner: pending_owner.clone(),
+            nonce,
+        }
+    }
+
+    /// Return the hash that the pending owner signs.
+    pub fn hash(&self) -> StdResult<[u8; 32]> {
+        Ok(Sha256::digest(to_json_vec(self)?).into())
+    }
+}
+
+impl OwnershipStore {
+    fn signature_nonces(&self) -> Option<Map<(&Addr, u64), Empty>> {
+        self.signature_nonces_namespace.map(Map::new)
+    }
+
+    /// Verify the pending owner's signature accepting the ownership, and mark
+    /// its nonce as used. Return the pending owner.
+    pub(crate) fn verify_signed_acceptance(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        signature: &Binary,
+        nonce: u64,
+    ) -> Result<Addr, OwnershipError> {
+        let Some(nonces) = self.signature_nonces() else {
+            return Err(OwnershipError::SignedAcceptanceDisabled);
+        };
+
+        let ownership = self.get_ownership(deps.storage, &env.block)?;
+
+        // there must be an existing ownership transfer
+        let Some(pending_owner) = ownership.pending_owner else {
+            return Err(OwnershipError::TransferNotFound);
+        };
+
+        // the owner must have committed to the pending owner's key
+        let Some(pubkey) = ownership.pending_pubkey else {
+            return Err(OwnershipError::PendingPubkeyNotFound);
+        };
+        pubkey.check_controls(deps.api, &pending_owner)?;
+
+        // the nonce must not have been used before
+        if nonces.has(deps.storage, (&pending_owner, nonce)) {
+            return Err(OwnershipError::NonceAlreadyUsed(nonce));
+        }
+
+        let payload = AcceptancePayload::new(
+            &env.contract.address,
+            &env.block.chain_id,
+            &pending_owner,
+            nonce,
+        );
+        pubkey.verify(deps.api, &payload.hash()?, signature)?;
+
+        nonces.save(deps.storage, (&pending_owner, nonce), &Empty {})?;
+
+        Ok(pending_owner)
+    }
+}
+
+//------------------------------------------------------------------------------
+// Tests
+//------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{
+        testing::{message_info, mock_dependencies, mock_env},
+        Addr,
+    };
+    use k256::ecdsa::{signature::hazmat::PrehashSigner, Signature, SigningKey};
+
+    use super::*;
+    use crate::{Action, OWNERSHIP_KEY};
+
+    const OWNERSHIP: OwnershipStore =
+        OwnershipStore::new(OWNERSHIP_KEY).with_signed_acceptance("nonces");
+
+    fn sign_secp256k1(key: &SigningKey, env: &Env, pending_owner: &Addr, nonce: u64) -> Binary {
+        let payload = AcceptancePayload::new(
+            &env.contract.address,
+            &env.block.chain_id,
+            pending_owner,
+            nonce,
+        );
+        let signature: Signature = key.sign_prehash(&payload.hash().unwrap()).unwrap();
+        signature.to_bytes().to_vec().into()
+    }
+
+    #[test]
+    fn accepting_ownership_with_signature() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let larry = deps.api.addr_make("larry");
+        let pumpkin = deps.api.addr_make("pumpkin");
+        let relayer = deps.api.addr_make("relayer");
+
+        // jake's account is controlled by the key
+        let key = SigningKey::from_slice(&[7; 32]).unwrap();
+        let pubkey = PublicKey::Secp256k1(key.verifying_key().to_sec1_bytes().to_vec().into());
+        let jake = deps.api.addr_humanize(&pubkey.canonical_address().unwrap()).unwrap();
+
+        OWNERSHIP.initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str())).unwrap();
+
+        let mut update = |sender: &Addr, action: Action| {
+            OWNERSHIP.execute_update_ownership(
+                deps.as_mut(),
+                &env,
+                &message_info(sender, &[]),
+                action,
+            )
+        };
+
+        // cannot accept with a signature if no key was committed
+        {
+            update(
+                &larry,
+                Action::TransferOwnership {
+                    new_owner: jake.to_string(),
+                    expiry: None,
+                    expires_in: None,
+                    delay: None,
+                    pubkey: None,
+                    price: None,
+                },
+            )
+            .unwrap();
+
+            let err = update(
+                &relayer,
+                Action::AcceptOwnershipWithSignature {
+                    signature: sign_secp256k1(&key, &env, &jake, 1),
+                    nonce: 1,
+                },
+            )
+            .unwrap_err();
+            assert_eq!(err, OwnershipError::PendingPubkeyNotFound);
+        }
+
+        // the key must control the pending owner's account, so the owner can't
+        // accept on someone else's behalf with a key of its own
+        {
+            let err = update(
+                &larry,
+                Action::TransferOwnership {
+                    new_owner: pumpkin.to_string(),
+                    expiry: None,
+                    expires_in: None,
+                    delay: None,
+                    pubkey: Some(pubkey.clone()),
+                    price: None,
+                },
+            )
+            .unwrap_err();
+            assert_eq!(err, OwnershipError::PubkeyMismatch);
+        }
+
+        update(
+            &larry,
+            Action::TransferOwnership {
+                new_owner: jake.to_string(),
+                expiry: None,
+                expires_in: None,
+                delay: None,
+                pubkey: Some(pubkey),
+                price: None,
+            },
+        )
+        .unwrap();
+
+        // the signature must be over the expected payload
+        {
+            let err = update(
+                &relayer,
+                Action::AcceptOwnershipWithSignature {
+                    signature: sign_secp256k1(&key, &env, &jake, 2),
+                    nonce: 1,
+                },
+            )
+            .unwrap_err();
+            assert_eq!(err, OwnershipError::InvalidSignature);
+
+            let other_key = SigningKey::from_slice(&[8; 32]).unwrap();
+            let err = update(
+                &relayer,
+                Action::AcceptOwnershipWithSignature {
+                    signature: sign_secp256k1(&other_key, &env, &jake, 1),
+                    nonce: 1,
+                },
+            )
+            .unwrap_err();
+            assert_eq!(err, OwnershipError::InvalidSignature);
+        }
+
+        // a relayer properly submits the pending owner's signature
+        {
+            let res = update(
+                &relayer,
+                Action::AcceptOwnershipWithSignature {
+                    signature: sign_secp256k1(&key, &env, &jake, 1),
+                    nonce: 1,
+                },
+            )
+            .unwrap();
+            assert_eq!(res.events[0].ty, "ownership_accepted");
+
+            let ownership = OWNERSHIP.get_ownership(deps.as_ref().storage, &env.block).unwrap();
+            assert_eq!(ownership.owner, Some(jake.clone()));
+            assert_eq!(ownership.pending_owner, None);
+            assert_eq!(ownership.pending_pubkey, None);
+        }
+
+        // the signature can't be replayed on a later transfer
+        {
+            let mut update = |sender: &Addr, action: Action| {
+                OWNERSHIP.execute_update_ownership(
+                    deps.as_mut(),
+                    &env,
+                    &message_info(sender, &[]),
+                    action,
+                )
+            };
+
+            update(
+                &jake,
+                Action::TransferOwnership {
+                    new_owner: larry.to_string(),
+                    expiry: None,
+                    expires_in: None,
+                    delay: None,
+                    pubkey: None,
+                    price: None,
+                },
+            )
+            .unwrap();
+            update(&larry, Action::AcceptOwnership).unwrap();
+            update(
+                &larry,
+                Action::TransferOwnership {
+                    new_owner: jake.to_string(),
+                    expiry: None,
+                    expires_in: None,
+                    delay: None,
+                    pubkey: Some(PublicKey::Secp256k1(
+                        key.verifying_key().to_sec1_bytes().to_vec().into(),
+                    )),
+                    price: None,
+                },
+            )
+            .unwrap();
+
+            let err = update(
+                &relayer,
+                Action::AcceptOwnershipWithSignature {
+                    signature: sign_secp256k1(&key, &env, &jake, 1),
+                    nonce: 1,
+                },
+            )
+            .unwrap_err();
+            assert_eq!(err, OwnershipError::NonceAlreadyUsed(1));
+        }
+    }
+
+    #[test]
+    fn verifying_ed25519_signature() {
+        let deps = mock_dependencies();
+        let env = mock_env();
+        let jake = deps.api.addr_make("jake");
+
+        let key = ed25519_zebra::SigningKey::from([7; 32]);
+        let pubkey = PublicKey::Ed25519(
+            <[u8; 32]>::from(ed25519_zebra::VerificationKey::from(&key)).to_vec().into(),
+        );
+
+        let hash = AcceptancePayload::new(&env.contract.address, &env.block.chain_id, &jake, 1)
+            .hash()
+            .unwrap();
+        let signature = <[u8; 64]>::from(key.sign(&hash));
+
+        assert!(pubkey.verify(&deps.api, &hash, &signature).is_ok());
+        assert_eq!(
+            pubkey.verify(&deps.api, &[0; 32], &signature).unwrap_err(),
+            OwnershipError::InvalidSignature,
+        );
+    }
+
+    #[test]
+    fn deriving_key_addresses() {
+        let mut deps = mock_dependencies();
+        let larry = deps.api.addr_make("larry");
+
+        // compressed and uncompressed secp256k1 keys control the same account
+        let key = SigningKey::from_slice(&[7; 32]).unwrap();
+        let compressed = key.verifying_key().to_encoded_point(true).as_bytes().to_vec();
+        let uncompressed = key.verifying_key().to_encoded_point(false).as_bytes().to_vec();
+        let address = PublicKey::Secp256k1(compressed.into()).canonical_address().unwrap();
+        assert_eq!(address.len(), 20);
+        assert_eq!(PublicKey::Secp256k1(uncompressed.into()).canonical_address(), Ok(address));
+
+        // malformed keys are rejected
+        assert_eq!(
+            PublicKey::Secp256k1(vec![0x02; 32].into()).canonical_address(),
+            Err(OwnershipError::InvalidPubkey),
+        );
+        assert_eq!(
+            PublicKey::Ed25519(vec![7; 33].into()).canonical_address(),
+            Err(OwnershipError::InvalidPubkey),
+        );
+
+        // a key can't be committed to if the ownership doesn't allow signed
+        // acceptances
+        let pubkey = PublicKey::Secp256k1(key.verifying_key().to_sec1_bytes().to_vec().into());
+        let jake = deps.api.addr_humanize(&pubkey.canonical_address().unwrap()).unwrap();
+        let store = OwnershipStore::new(OWNERSHIP_KEY);
+        store.initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str())).unwrap();
+        let err = store
+            .update_ownership(
+                deps.as_mut(),
+                &mock_env().block,
+                &larry,
+                Action::TransferOwnership {
+                    new_owner: jake.to_string(),
+                    expiry: None,
+                    expires_in: None,
+                    delay: None,
+                    pubkey: Some(pubkey),
+                    price: None,
+                },
+            )
+            .unwrap_err();
+        assert_eq!(err, OwnershipError::SignedAcceptanceDisabled);
+    }
+}
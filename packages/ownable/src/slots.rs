@@ -0,0 +1,188 @@
+use cosmwasm_std::{
+    Addr, Binary, BlockInfo, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult,
+    Storage,
+};
+
+use crate::{Action, Ownership, OwnershipError, OwnershipQuery, OwnershipStore};
+
+/// A fixed set of named ownership stores, for contracts that have several
+/// independently owned roles, e.g. an "owner" and a "treasury" controller.
+///
+/// ```rust
+/// use cw_ownable::{OwnershipSlots, OwnershipStore};
+///
+/// const SLOTS: OwnershipSlots<2> = OwnershipSlots::new([
+///     ("owner", OwnershipStore::new("ownership")),
+///     ("treasury", OwnershipStore::new("treasury_ownership")),
+/// ]);
+/// ```
+pub struct OwnershipSlots<const N: usize> {
+    pub slots: [(&'static str, OwnershipStore); N],
+}
+
+impl<const N: usize> OwnershipSlots<N> {
+    pub const fn new(slots: [(&'static str, OwnershipStore); N]) -> Self {
+        Self {
+            slots,
+        }
+    }
+
+    /// Return the names of the slots, in the order they were declared.
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.slots.iter().map(|(name, _)| *name)
+    }
+
+    /// Return the ownership store of the given slot.
+    /// Return Err if no slot has the given name.
+    pub fn get(&self, slot: &str) -> Result<&OwnershipStore, OwnershipError> {
+        self.slots
+            .iter()
+            .find(|(name, _)| *name == slot)
+            .map(|(_, store)| store)
+            .ok_or_else(|| OwnershipError::UnknownOwnershipSlot(slot.into()))
+    }
+
+    /// Assert that an account is the owner of the given slot at the given block,
+    /// taking into account whether the slot's lease has ended.
+    pub fn assert_owner(
+        &self,
+        store: &dyn Storage,
+        block: &BlockInfo,
+        slot: &str,
+        sender: &Addr,
+    ) -> Result<(), OwnershipError> {
+        self.get(slot)?.assert_owner(store, block, sender)
+    }
+
+    /// Update the ownership of the given slot, returning a response which
+    /// contains the event describing the change, tagged with the slot's name.
+    pub fn execute_update_ownership(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        slot: &str,
+        action: Action,
+    ) -> Result<Response, OwnershipError> {
+        let res = self.get(slot)?.execute_update_ownership(deps, env, info, action)?;
+        Ok(res.add_attribute("slot", slot))
+    }
+
+    /// Handle a query about the ownership of the given slot, serializing the
+    /// response.
+    pub fn query_ownership(
+        &self,
+        deps: Deps,
+        env: &Env,
+        slot: &str,
+        query: OwnershipQuery,
+    ) -> StdResult<Binary> {
+        let store = self.get(slot).map_err(|err| StdError::generic_err(err.to_string()))?;
+        store.query_ownership(deps, env, query)
+    }
+
+    /// Get the ownership value of the given slot as it stands at the given
+    /// block.
+    pub fn get_ownership(
+        &self,
+        storage: &dyn Storage,
+        block: &BlockInfo,
+        slot: &str,
+    ) -> Result<Ownership<Addr>, OwnershipError> {
+        Ok(self.get(slot)?.get_ownership(storage, block)?)
+    }
+
+    /// Get the ownership values of all slots as they stand at the given block,
+    /// in the order they were declared.
+    pub fn get_all_ownerships(
+        &self,
+        storage: &dyn Storage,
+        block: &BlockInfo,
+    ) -> StdResult<Vec<(String, Ownership<Addr>)>> {
+        self.slots
+            .iter()
+            .map(|(name, store)| Ok((name.to_string(), store.get_ownership(storage, block)?)))
+            .collect()
+    }
+}
+
+//------------------------------------------------------------------------------
+// Tests
+//------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+
+    use super::*;
+
+    const SLOTS: OwnershipSlots<2> = OwnershipSlots::new([
+        ("owner", OwnershipStore::new("ownership")),
+        ("treasury", OwnershipStore::new("treasury_ownership")),
+    ]);
+
+    #[test]
+    fn dispatching_to_slots() {
+        let mut deps = mock_dependencies();
+        let block = mock_env().block;
+        let larry = deps.api.addr_make("larry");
+        let jake = deps.api.addr_make("jake");
+
+        SLOTS
+            .get("owner")
+            .unwrap()
+            .initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str()))
+            .unwrap();
+        SLOTS
+            .get("treasury")
+            .unwrap()
+            .initialize_owner(&mut deps.storage, &deps.api, Some(jake.as_str()))
+            .unwrap();
+
+        assert_eq!(SLOTS.names().collect::<Vec<_>>(), ["owner", "treasury"]);
+
+        // the owner of one slot can't act on another
+        {
+            let err = SLOTS
+                .execute_update_ownership(
+                    deps.as_mut(),
+                    &mock_env(),
+                    &message_info(&larry, &[]),
+                    "treasury",
+                    Action::RenounceOwnership,
+                )
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::NotOwner);
+            assert!(SLOTS.assert_owner(deps.as_ref().storage, &block, "treasury", &larry).is_err());
+        }
+
+        // unknown slots are rejected
+        {
+            let err = SLOTS.get_ownership(deps.as_ref().storage, &block, "vault").unwrap_err();
+            assert_eq!(err, OwnershipError::UnknownOwnershipSlot("vault".into()));
+        }
+
+        // the action is applied to the named slot only
+        {
+            let res = SLOTS
+                .execute_update_ownership(
+                    deps.as_mut(),
+                    &mock_env(),
+                    &message_info(&jake, &[]),
+                    "treasury",
+                    Action::RenounceOwnership,
+                )
+                .unwrap();
+            assert_eq!(res.attributes, [("slot", "treasury")]);
+
+            let treasury = SLOTS.get_ownership(deps.as_ref().storage, &block, "treasury").unwrap();
+            assert_eq!(treasury.owner, None);
+
+            let owner = SLOTS.get_ownership(deps.as_ref().storage, &block, "owner").unwrap();
+            assert_eq!(owner.owner, Some(larry));
+
+            let ownerships = SLOTS.get_all_ownerships(deps.as_ref().storage, &block).unwrap();
+            assert_eq!(ownerships, [("owner".into(), owner), ("treasury".into(), treasury)]);
+        }
+    }
+}
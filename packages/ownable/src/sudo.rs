@@ -0,0 +1,290 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{DepsMut, Env, Response};
+
+use crate::{
+    admin_sync_msg, history, ownership_event, Activity, ChangeKind, Ownership, OwnershipError,
+    OwnershipStore,
+};
+
+/// Actions that the chain's governance can take, through the contract's `sudo`
+/// entry point, to force a change to the contract's ownership.
+///
+/// These actions bypass the ownership checks, as they can only be dispatched
+/// by the chain itself.
+#[cw_serde]
+pub enum SudoAction {
+    /// Appoint the given account as the contract's owner, regardless of
+    /// whether the contract currently has an owner. This also recovers a
+    /// contract whose ownership has been renounced.
+    ///
+    /// The ownership starts afresh: any existing pending ownership transfer or
+    /// proposed renouncement is canceled, and the previous owner's lease and
+    /// recovery address are removed, as they could otherwise hand the
+    /// ownership to an account chosen before governance stepped in. The
+    /// removed fallback owner and recovery address are included in the event.
+    SetOwner {
+        owner: String,
+    },
+
+    /// Cancel the pending ownership transfer.
+    ClearPendingTransfer,
+}
+
+impl From<&SudoAction> for ChangeKind {
+    fn from(action: &SudoAction) -> Self {
+        match action {
+            SudoAction::SetOwner {
+                ..
+            } => ChangeKind::OwnerSetBySudo,
+            SudoAction::ClearPendingTransfer => ChangeKind::TransferClearedBySudo,
+        }
+    }
+}
+
+impl OwnershipStore {
+    /// Force a change to the contract's ownership, bypassing the ownership
+    /// checks. Return a response containing a wasm event that describes the
+    /// change, and, if admin sync is enabled, the message that updates the
+    /// admin.
+    ///
+    /// As sudo messages have no sender, the contract itself is recorded as the
+    /// actor, both in the event and in the history, if enabled.
+    ///
+    /// This function is only intended to be used in the contract's `sudo` entry
+    /// point.
+    pub fn sudo_update_ownership(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        action: SudoAction,
+    ) -> Result<Response, OwnershipError> {
+        let kind = ChangeKind::from(&action);
+        let actor = &env.contract.address;
+
+        // the ownership may not have been initialized, e.g. if the contract was
+        // instantiated before adopting this crate
+        let previous_ownership = self.item.may_load(deps.storage)?.unwrap_or(Ownership::new(None));
+
+        let ownership = match action {
+            SudoAction::SetOwner {
+                owner,
+            } => Ownership {
+                // the new owner's inactivity is counted from now
+                last_activity: Some(Activity::at(&env.block)),
+                ..Ownership::new(Some(deps.api.addr_validate(&owner)?))
+            },
+            SudoAction::ClearPendingTransfer => {
+                // there must be an existing ownership transfer
+                if previous_ownership.pending_owner.is_none() {
+                    return Err(OwnershipError::TransferNotFound);
+                }
+
+                Ownership {
+                    pending_owner: None,
+                    pending_expiry: None,
+                    pending_accept_after: None,
+                    pending_pubkey: None,
+                    pending_price: None,
+                    ..previous_ownership.clone()
+                }
+            },
+        };
+
+        self.item.save(deps.storage, &ownership)?;
+
+        if let Some(history) = self.history() {
+            history::record_change(
+                &history,
+                deps.storage,
+                &env.block,
+                actor,
+                kind,
+                previous_ownership.clone(),
+                ownership.clone(),
+            )?;
+        }
+
+        let mut event = ownership_event(kind, actor, &previous_ownership, &ownership);
+        if kind == ChangeKind::OwnerSetBySudo {
+            if let Some(fallback_owner) = &previous_ownership.fallback_owner {
+                event = event.add_attribute("removed_fallback_owner", fallback_owner);
+            }
+            if let Some(recovery_address) = &previous_ownership.recovery_address {
+                event = event.add_attribute("removed_recovery_address", recovery_address);
+            }
+        }
+        let admin_msg = self
+            .sync_admin
+            .then(|| admin_sync_msg(actor, &previous_ownership, &ownership))
+            .flatten();
+        Ok(Response::new().add_event(event).add_messages(admin_msg))
+    }
+}
+
+//------------------------------------------------------------------------------
+// Tests
+//------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env},
+        Addr,
+    };
+
+    use super::*;
+    use crate::{testing::mock_addresses, Duration, Expiration, OWNERSHIP_KEY};
+
+    const OWNERSHIP: OwnershipStore = OwnershipStore::new(OWNERSHIP_KEY).with_history("history");
+
+    #[test]
+    fn overriding_ownership() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let larry = deps.api.addr_make("larry");
+        let jake = deps.api.addr_make("jake");
+
+        // cannot clear a transfer that doesn't exist
+        {
+            let err = OWNERSHIP
+                .sudo_update_ownership(deps.as_mut(), &env, SudoAction::ClearPendingTransfer)
+                .unwrap_err();
+            assert_eq!(err, OwnershipError::TransferNotFound);
+        }
+
+        // governance properly sets the owner of an uninitialized ownership
+        {
+            let res = OWNERSHIP
+                .sudo_update_ownership(
+                    deps.as_mut(),
+                    &env,
+                    SudoAction::SetOwner {
+                        owner: larry.to_string(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(res.events[0].ty, "ownership_set_by_sudo");
+
+            let ownership = OWNERSHIP.get_ownership(deps.as_ref().storage, &env.block).unwrap();
+            assert_eq!(ownership.owner, Some(larry.clone()));
+        }
+
+        // governance properly clears a pending transfer
+        {
+            OWNERSHIP
+                .item
+                .save(
+                    deps.as_mut().storage,
+                    &Ownership {
+                        owner: Some(larry.clone()),
+                        pending_owner: Some(jake.clone()),
+                        pending_expiry: Some(Expiration::AtHeight(42069)),
+                        pending_accept_after: None,
+                        pending_pubkey: None,
+                        pending_price: None,
+                        pending_renounce: None,
+                        lease_expiry: None,
+                        fallback_owner: None,
+                        recovery_address: None,
+                        last_activity: None,
+                    },
+                )
+                .unwrap();
+
+            let res = OWNERSHIP
+                .sudo_update_ownership(deps.as_mut(), &env, SudoAction::ClearPendingTransfer)
+                .unwrap();
+            assert_eq!(res.events[0].ty, "ownership_transfer_cleared_by_sudo");
+
+            let ownership = OWNERSHIP.get_ownership(deps.as_ref().storage, &env.block).unwrap();
+            assert_eq!(ownership.owner, Some(larry.clone()));
+            assert_eq!(ownership.pending_owner, None);
+            assert_eq!(ownership.pending_expiry, None);
+        }
+
+        // governance properly recovers a renounced ownership
+        {
+            OWNERSHIP.initialize_owner(&mut deps.storage, &deps.api, None).unwrap();
+
+            OWNERSHIP
+                .sudo_update_ownership(
+                    deps.as_mut(),
+                    &env,
+                    SudoAction::SetOwner {
+                        owner: jake.to_string(),
+                    },
+                )
+                .unwrap();
+
+            let ownership = OWNERSHIP.get_ownership(deps.as_ref().storage, &env.block).unwrap();
+            assert_eq!(ownership.owner, Some(jake));
+        }
+
+        // the changes are recorded, with the contract as the actor
+        {
+            let history = OWNERSHIP.query_history(deps.as_ref().storage, None, None).unwrap();
+            assert_eq!(history.len(), 3);
+            assert!(history.iter().all(|change| change.actor == env.contract.address));
+            assert_eq!(history[0].previous_ownership.owner, None::<Addr>);
+            assert_eq!(history[1].kind, ChangeKind::TransferClearedBySudo);
+        }
+    }
+
+    #[test]
+    fn setting_owner_removes_lease_and_recovery() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let [larry, jake, pumpkin] = mock_addresses(&deps.api);
+        let store = OwnershipStore::new(OWNERSHIP_KEY).with_recovery_period(Duration::Height(100));
+
+        store
+            .item
+            .save(
+                deps.as_mut().storage,
+                &Ownership {
+                    lease_expiry: Some(Expiration::AtHeight(42069)),
+                    fallback_owner: Some(jake.clone()),
+                    recovery_address: Some(pumpkin.clone()),
+                    last_activity: Some(Activity::at(&env.block)),
+                    ..Ownership::new(Some(larry))
+                },
+            )
+            .unwrap();
+
+        let mut block = env.block.clone();
+        block.height += 50;
+        let env = Env {
+            block,
+            ..env
+        };
+        let owner = deps.api.addr_make("owner");
+        let res = store
+            .sudo_update_ownership(
+                deps.as_mut(),
+                &env,
+                SudoAction::SetOwner {
+                    owner: owner.to_string(),
+                },
+            )
+            .unwrap();
+
+        // the ownership starts afresh, with the new owner active from now
+        let ownership = store.get_ownership(deps.as_ref().storage, &env.block).unwrap();
+        assert_eq!(
+            ownership,
+            Ownership {
+                last_activity: Some(Activity::at(&env.block)),
+                ..Ownership::new(Some(owner))
+            },
+        );
+
+        // the removed accounts are included in the event
+        let attributes = &res.events[0].attributes;
+        assert!(attributes
+            .iter()
+            .any(|attr| attr.key == "removed_fallback_owner" && attr.value == jake.as_str()));
+        assert!(attributes
+            .iter()
+            .any(|attr| attr.key == "removed_recovery_address" && attr.value == pumpkin.as_str()));
+    }
+}
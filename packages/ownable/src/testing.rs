@@ -0,0 +1,15 @@
+use cosmwasm_std::{testing::MockApi, Addr, BlockInfo, Timestamp};
+
+/// Return the addresses of larry, jake and pumpkin, in that order.
+pub(crate) fn mock_addresses(api: &MockApi) -> [Addr; 3] {
+    [api.addr_make("larry"), api.addr_make("jake"), api.addr_make("pumpkin")]
+}
+
+/// Return a block at the given height, at a fixed time.
+pub(crate) fn mock_block_at_height(height: u64) -> BlockInfo {
+    BlockInfo {
+        height,
+        time: Timestamp::from_seconds(10000),
+        chain_id: "".into(),
+    }
+}
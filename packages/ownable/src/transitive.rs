@@ -0,0 +1,252 @@
+use cosmwasm_std::{
+    from_json, to_json_binary, to_json_vec, Addr, BlockInfo, ContractResult, Empty, QuerierWrapper,
+    QueryRequest, StdError, Storage, SystemError, SystemResult, WasmQuery,
+};
+
+use crate::{Ownership, OwnershipError, OwnershipQuery, OwnershipStore};
+
+impl OwnershipStore {
+    /// Assert that an account is the contract's owner, either directly or by
+    /// owning the owner contract, up to `max_depth` levels of owner contracts.
+    /// Return the path followed, from the contract's owner to the account.
+    ///
+    /// The contract's owner is the one at the given block, taking into account
+    /// whether the lease has ended. The owner of each contract along the path
+    /// is queried with the `Ownership {}` query inserted by `cw_ownable_query`.
+    /// The path ends at an account that isn't a contract, at a contract whose
+    /// query returns an error, e.g. because it doesn't support the query, or at
+    /// a contract that has no owner.
+    ///
+    /// Return Err if the account isn't found along the path, if the path leads
+    /// back to an owner already visited, or if the querier itself fails.
+    pub fn assert_owner_transitive(
+        &self,
+        querier: QuerierWrapper,
+        store: &dyn Storage,
+        block: &BlockInfo,
+        sender: &Addr,
+        max_depth: u32,
+    ) -> Result<Vec<Addr>, OwnershipError> {
+        let ownership = self.get_ownership(store, block)?;
+        let Some(mut owner) = ownership.owner else {
+            return Err(OwnershipError::NoOwner);
+        };
+
+        let mut path = vec![];
+        for depth in 0..=max_depth {
+            path.push(owner.clone());
+
+            if owner == *sender {
+                return Ok(path);
+            }
+
+            if depth == max_depth {
+                break;
+            }
+
+            // an account that isn't a cw-ownable contract ends the path
+            let Some(next) = query_owner(querier, &owner)? else {
+                break;
+            };
+
+            if path.contains(&next) {
+                path.push(next);
+                return Err(OwnershipError::OwnershipCycle(path));
+            }
+
+            owner = next;
+        }
+
+        Err(OwnershipError::NotTransitiveOwner(path))
+    }
+}
+
+/// Query the owner of the given contract. Return `None` if the account isn't a
+/// contract, if the contract returns an error, or if it has no owner.
+///
+/// The chain redacts the errors returned by contracts queried from another
+/// contract, so an error can't be told apart from an unsupported query, and
+/// any error ends the path. Return Err if the querier itself fails, or if the
+/// response isn't an ownership.
+fn query_owner(querier: QuerierWrapper, contract: &Addr) -> Result<Option<Addr>, OwnershipError> {
+    let request: QueryRequest<Empty> = WasmQuery::Smart {
+        contract_addr: contract.to_string(),
+        msg: to_json_binary(&OwnershipQuery::Ownership {})?,
+    }
+    .into();
+    let raw = to_json_vec(&request)?;
+
+    let res = match querier.raw_query(&raw) {
+        SystemResult::Err(SystemError::NoSuchContract {
+            ..
+        }) => return Ok(None),
+        SystemResult::Err(err) => {
+            return Err(StdError::generic_err(format!("Querier system error: {err}")).into());
+        },
+        SystemResult::Ok(ContractResult::Err(_)) => return Ok(None),
+        SystemResult::Ok(ContractResult::Ok(res)) => res,
+    };
+
+    let ownership: Ownership<Addr> = from_json(res)?;
+    Ok(ownership.owner)
+}
+
+/// Format a path of owners for an error message.
+pub(crate) fn format_path(path: &[Addr]) -> String {
+    path.iter().map(Addr::as_str).collect::<Vec<_>>().join(" -> ")
+}
+
+//------------------------------------------------------------------------------
+// Tests
+//------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, MockApi};
+
+    use super::*;
+    use crate::OWNERSHIP_KEY;
+
+    const OWNERSHIP: OwnershipStore = OwnershipStore::new(OWNERSHIP_KEY);
+
+    fn ownership(owner: &Addr) -> Ownership<Addr> {
+        Ownership::new(Some(owner.clone()))
+    }
+
+    #[test]
+    fn asserting_transitive_owner() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let api = MockApi::default();
+        let [dao, council, larry, jake, vault, broken] =
+            ["dao", "council", "larry", "jake", "vault", "broken"].map(|s| api.addr_make(s));
+
+        // the contract is owned by the dao, which is owned by the council, which
+        // is owned by larry
+        let owners = HashMap::from([
+            (dao.to_string(), ownership(&council)),
+            (council.to_string(), ownership(&larry)),
+        ]);
+        let (vault_addr, broken_addr) = (vault.to_string(), broken.to_string());
+        deps.querier.update_wasm(move |query| match query {
+            WasmQuery::Smart {
+                contract_addr,
+                msg,
+            } => {
+                assert_eq!(from_json::<OwnershipQuery>(msg).unwrap(), OwnershipQuery::Ownership {});
+                match owners.get(contract_addr) {
+                    Some(ownership) => {
+                        SystemResult::Ok(ContractResult::Ok(to_json_binary(ownership).unwrap()))
+                    },
+                    // a contract that doesn't support the ownership query, whose
+                    // error is redacted by the chain
+                    None if *contract_addr == vault_addr => {
+                        SystemResult::Ok(ContractResult::Err("error code 5: redacted".into()))
+                    },
+                    // a query the querier fails to handle
+                    None if *contract_addr == broken_addr => {
+                        SystemResult::Err(SystemError::Unknown {})
+                    },
+                    None => SystemResult::Err(SystemError::NoSuchContract {
+                        addr: contract_addr.clone(),
+                    }),
+                }
+            },
+            _ => unimplemented!(),
+        });
+
+        OWNERSHIP.initialize_owner(&mut deps.storage, &deps.api, Some(dao.as_str())).unwrap();
+
+        // other contracts owned by the vault and by the broken contract
+        let vault_ownership = OwnershipStore::new("vault_ownership");
+        vault_ownership
+            .initialize_owner(&mut deps.storage, &deps.api, Some(vault.as_str()))
+            .unwrap();
+        let broken_ownership = OwnershipStore::new("broken_ownership");
+        broken_ownership
+            .initialize_owner(&mut deps.storage, &deps.api, Some(broken.as_str()))
+            .unwrap();
+
+        let assert = |store: &OwnershipStore, sender: &Addr, max_depth: u32| {
+            store.assert_owner_transitive(
+                deps.as_ref().querier,
+                deps.as_ref().storage,
+                &env.block,
+                sender,
+                max_depth,
+            )
+        };
+
+        // the direct owner
+        assert_eq!(assert(&OWNERSHIP, &dao, 0), Ok(vec![dao.clone()]));
+
+        // owners of the owner, within the maximum depth
+        assert_eq!(assert(&OWNERSHIP, &council, 1), Ok(vec![dao.clone(), council.clone()]));
+        assert_eq!(
+            assert(&OWNERSHIP, &larry, 2),
+            Ok(vec![dao.clone(), council.clone(), larry.clone()])
+        );
+        assert_eq!(
+            assert(&OWNERSHIP, &larry, 1),
+            Err(OwnershipError::NotTransitiveOwner(vec![dao.clone(), council.clone()])),
+        );
+
+        // the path ends at an account that isn't a contract
+        assert_eq!(
+            assert(&OWNERSHIP, &jake, 5),
+            Err(OwnershipError::NotTransitiveOwner(vec![
+                dao.clone(),
+                council.clone(),
+                larry.clone()
+            ])),
+        );
+
+        // the path ends at a contract that doesn't support the ownership query
+        assert_eq!(
+            assert(&vault_ownership, &jake, 5),
+            Err(OwnershipError::NotTransitiveOwner(vec![vault.clone()])),
+        );
+
+        // errors of the querier itself are returned
+        assert!(matches!(assert(&broken_ownership, &jake, 5), Err(OwnershipError::Std(_))));
+    }
+
+    #[test]
+    fn detecting_ownership_cycles() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let api = MockApi::default();
+        let [dao, council, jake] = ["dao", "council", "jake"].map(|s| api.addr_make(s));
+
+        // the dao and the council own each other
+        let owners = HashMap::from([
+            (dao.to_string(), ownership(&council)),
+            (council.to_string(), ownership(&dao)),
+        ]);
+        deps.querier.update_wasm(move |query| match query {
+            WasmQuery::Smart {
+                contract_addr,
+                ..
+            } => {
+                let ownership = &owners[contract_addr];
+                SystemResult::Ok(ContractResult::Ok(to_json_binary(ownership).unwrap()))
+            },
+            _ => unimplemented!(),
+        });
+
+        OWNERSHIP.initialize_owner(&mut deps.storage, &deps.api, Some(dao.as_str())).unwrap();
+
+        let err = OWNERSHIP
+            .assert_owner_transitive(
+                deps.as_ref().querier,
+                deps.as_ref().storage,
+                &env.block,
+                &jake,
+                10,
+            )
+            .unwrap_err();
+        assert_eq!(err, OwnershipError::OwnershipCycle(vec![dao.clone(), council.clone(), dao]));
+    }
+}
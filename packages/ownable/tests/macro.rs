@@ -1,5 +1,15 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cw_ownable::{cw_ownable_execute, cw_ownable_query, Action};
+use cosmwasm_std::{
+    from_json,
+    testing::{message_info, mock_dependencies, mock_env},
+    Addr, Deps, DepsMut, Env, MessageInfo,
+};
+use cw_ownable::{
+    cw_ownable_execute, cw_ownable_query, cw_ownable_sudo, cw_pausable_execute, cw_pausable_query,
+    cw_roles_execute, cw_roles_query, only_owner, Action, Activity, Duration, Expiration,
+    Ownership, OwnershipChange, OwnershipError, OwnershipSlots, OwnershipStore, PauseAction,
+    PendingOwnership, RoleAction, SudoAction,
+};
 
 #[cw_ownable_execute]
 #[cw_serde]
@@ -27,6 +37,159 @@ enum QueryMsg {
     },
 }
 
+#[cw_roles_execute]
+#[cw_ownable_execute]
+#[cw_serde]
+enum RolesExecuteMsg {
+    Foo,
+}
+
+#[cw_roles_query]
+#[cw_ownable_query]
+#[cw_serde]
+#[derive(QueryResponses)]
+enum RolesQueryMsg {
+    #[returns(String)]
+    Foo,
+}
+
+#[cw_ownable_query(history)]
+#[cw_serde]
+#[derive(QueryResponses)]
+enum HistoryQueryMsg {
+    #[returns(String)]
+    Foo,
+}
+
+#[cw_pausable_execute]
+#[cw_serde]
+enum PausableExecuteMsg {
+    Foo,
+}
+
+#[cw_pausable_query]
+#[cw_serde]
+#[derive(QueryResponses)]
+enum PausableQueryMsg {
+    #[returns(String)]
+    Foo,
+}
+
+#[cw_ownable_execute(variant = "UpdateAdmin", style = "struct", doc = "Update the admin")]
+#[cw_serde]
+enum ConfiguredExecuteMsg {
+    Foo,
+}
+
+#[cw_ownable_query(variant = "Admin", doc = "Query the admin", history, recovery)]
+#[cw_serde]
+#[derive(QueryResponses)]
+enum ConfiguredQueryMsg {
+    #[returns(String)]
+    Foo,
+}
+
+#[cw_ownable_execute]
+#[cw_ownable_execute(slot = "treasury_controller")]
+#[cw_serde]
+enum SlotsExecuteMsg {
+    Foo,
+}
+
+#[cw_ownable_query]
+#[cw_ownable_query(slot = "treasury_controller", history)]
+#[cw_serde]
+#[derive(QueryResponses)]
+enum SlotsQueryMsg {
+    #[returns(String)]
+    Foo,
+}
+
+#[cw_ownable_sudo]
+#[cw_serde]
+enum SudoMsg {
+    Foo,
+}
+
+const TREASURY: OwnershipStore =
+    OwnershipStore::new("treasury").with_recovery_period(Duration::Time(1000));
+
+const SLOTS: OwnershipSlots<1> = OwnershipSlots::new([("treasury", TREASURY)]);
+
+const CREATOR: OwnershipStore = OwnershipStore::new("creator").with_history("creator_history");
+
+#[cw_ownable_execute(dispatch)]
+#[cw_serde]
+enum DispatchExecuteMsg {
+    Foo,
+}
+
+#[cw_ownable_query(dispatch)]
+#[cw_serde]
+#[derive(QueryResponses)]
+enum DispatchQueryMsg {
+    #[returns(String)]
+    Foo,
+}
+
+#[cw_ownable_query(variant = "Creator", history, dispatch, store = CREATOR)]
+#[cw_serde]
+#[derive(QueryResponses)]
+enum HistoryDispatchQueryMsg {
+    #[returns(String)]
+    Foo,
+}
+
+#[cw_ownable_execute(slot = "treasury", style = "struct", dispatch, store = SLOTS)]
+#[cw_serde]
+enum SlotsDispatchExecuteMsg {
+    Foo,
+}
+
+#[cw_ownable_query(slot = "treasury", dispatch, store = SLOTS)]
+#[cw_serde]
+#[derive(QueryResponses)]
+enum SlotsDispatchQueryMsg {
+    #[returns(String)]
+    Foo,
+}
+
+#[derive(Debug, PartialEq)]
+enum ContractError {
+    Ownership(OwnershipError),
+}
+
+impl From<OwnershipError> for ContractError {
+    fn from(err: OwnershipError) -> Self {
+        ContractError::Ownership(err)
+    }
+}
+
+#[only_owner]
+fn execute_foo(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    value: u64,
+) -> Result<u64, ContractError> {
+    Ok(value)
+}
+
+#[only_owner(store = TREASURY)]
+fn execute_bar(deps: DepsMut, _env: Env, info: &MessageInfo) -> Result<u64, ContractError> {
+    Ok(69)
+}
+
+#[only_owner]
+fn execute_baz(deps: DepsMut, info: MessageInfo) -> Result<u64, ContractError> {
+    Ok(1337)
+}
+
+#[only_owner(store = TREASURY)]
+fn query_bar(deps: Deps, env: &Env, info: MessageInfo) -> Result<u64, ContractError> {
+    Ok(420)
+}
+
 #[test]
 fn derive_execute_variants() {
     let msg = ExecuteMsg::Foo;
@@ -36,9 +199,37 @@ fn derive_execute_variants() {
         ExecuteMsg::UpdateOwnership(Action::TransferOwnership {
             new_owner: _,
             expiry: _,
+            expires_in: _,
+            delay: _,
+            pubkey: _,
+            price: _,
         })
         | ExecuteMsg::UpdateOwnership(Action::AcceptOwnership)
+        | ExecuteMsg::UpdateOwnership(Action::AcceptOwnershipWithSignature {
+            signature: _,
+            nonce: _,
+        })
         | ExecuteMsg::UpdateOwnership(Action::RenounceOwnership)
+        | ExecuteMsg::UpdateOwnership(Action::ProposeRenounce {
+            delay: _,
+        })
+        | ExecuteMsg::UpdateOwnership(Action::ConfirmRenounce)
+        | ExecuteMsg::UpdateOwnership(Action::CancelRenounce)
+        | ExecuteMsg::UpdateOwnership(Action::CancelTransfer)
+        | ExecuteMsg::UpdateOwnership(Action::RejectTransfer)
+        | ExecuteMsg::UpdateOwnership(Action::SetLease {
+            expiry: _,
+            fallback_owner: _,
+        })
+        | ExecuteMsg::UpdateOwnership(Action::ExtendLease {
+            expiry: _,
+        })
+        | ExecuteMsg::UpdateOwnership(Action::EndLease)
+        | ExecuteMsg::UpdateOwnership(Action::Heartbeat)
+        | ExecuteMsg::UpdateOwnership(Action::SetRecoveryAddress {
+            address: _,
+        })
+        | ExecuteMsg::UpdateOwnership(Action::ClaimRecovery)
         | ExecuteMsg::Foo
         | ExecuteMsg::Bar(_)
         | ExecuteMsg::Fuzz {
@@ -54,6 +245,10 @@ fn derive_query_variants() {
     // If this compiles we have won.
     match msg {
         QueryMsg::Ownership {}
+        | QueryMsg::IsOwner {
+            address: _,
+        }
+        | QueryMsg::PendingOwnership {}
         | QueryMsg::Foo
         | QueryMsg::Bar(_)
         | QueryMsg::Fuzz {
@@ -61,3 +256,313 @@ fn derive_query_variants() {
         } => "yay",
     };
 }
+
+#[test]
+fn derive_roles_execute_variants() {
+    let msg = RolesExecuteMsg::Foo;
+
+    // If this compiles we have won.
+    match msg {
+        RolesExecuteMsg::UpdateRoles(RoleAction::GrantRole {
+            role: _,
+            account: _,
+        })
+        | RolesExecuteMsg::UpdateRoles(RoleAction::RevokeRole {
+            role: _,
+            account: _,
+        })
+        | RolesExecuteMsg::UpdateRoles(RoleAction::RenounceRole {
+            role: _,
+        })
+        | RolesExecuteMsg::UpdateOwnership(_)
+        | RolesExecuteMsg::Foo => "yay",
+    };
+}
+
+#[test]
+fn derive_roles_query_variants() {
+    let msg = RolesQueryMsg::Foo;
+
+    // If this compiles we have won.
+    match msg {
+        RolesQueryMsg::HasRole {
+            role: _,
+            address: _,
+        }
+        | RolesQueryMsg::RoleMembers {
+            role: _,
+            start_after: _,
+            limit: _,
+        }
+        | RolesQueryMsg::Ownership {}
+        | RolesQueryMsg::IsOwner {
+            address: _,
+        }
+        | RolesQueryMsg::PendingOwnership {}
+        | RolesQueryMsg::Foo => "yay",
+    };
+}
+
+#[test]
+fn derive_history_query_variants() {
+    let msg = HistoryQueryMsg::Foo;
+
+    // If this compiles we have won.
+    match msg {
+        HistoryQueryMsg::Ownership {}
+        | HistoryQueryMsg::IsOwner {
+            address: _,
+        }
+        | HistoryQueryMsg::PendingOwnership {}
+        | HistoryQueryMsg::OwnershipHistory {
+            start_after: _,
+            limit: _,
+        }
+        | HistoryQueryMsg::Foo => "yay",
+    };
+}
+
+#[test]
+fn derive_pausable_execute_variants() {
+    let msg = PausableExecuteMsg::Foo;
+
+    // If this compiles we have won.
+    match msg {
+        PausableExecuteMsg::UpdatePause(PauseAction::Pause {
+            until: _,
+        })
+        | PausableExecuteMsg::UpdatePause(PauseAction::Unpause)
+        | PausableExecuteMsg::UpdatePause(PauseAction::SetPauser {
+            pauser: _,
+        })
+        | PausableExecuteMsg::Foo => "yay",
+    };
+}
+
+#[test]
+fn derive_pausable_query_variants() {
+    let msg = PausableQueryMsg::Foo;
+
+    // If this compiles we have won.
+    match msg {
+        PausableQueryMsg::PauseInfo {} | PausableQueryMsg::Foo => "yay",
+    };
+}
+
+#[test]
+fn derive_configured_execute_variants() {
+    let msg = ConfiguredExecuteMsg::Foo;
+
+    // If this compiles we have won.
+    match msg {
+        ConfiguredExecuteMsg::UpdateAdmin {
+            action: _,
+        }
+        | ConfiguredExecuteMsg::Foo => "yay",
+    };
+}
+
+#[test]
+fn derive_configured_query_variants() {
+    let msg = ConfiguredQueryMsg::Foo;
+
+    // If this compiles we have won.
+    match msg {
+        ConfiguredQueryMsg::Admin {}
+        | ConfiguredQueryMsg::IsAdmin {
+            address: _,
+        }
+        | ConfiguredQueryMsg::PendingAdmin {}
+        | ConfiguredQueryMsg::AdminHistory {
+            start_after: _,
+            limit: _,
+        }
+        | ConfiguredQueryMsg::AdminRecoveryStatus {}
+        | ConfiguredQueryMsg::Foo => "yay",
+    };
+}
+
+#[test]
+fn derive_slots_execute_variants() {
+    let msg = SlotsExecuteMsg::Foo;
+
+    // If this compiles we have won.
+    match msg {
+        SlotsExecuteMsg::UpdateOwnership(_)
+        | SlotsExecuteMsg::UpdateTreasuryControllerOwnership(_)
+        | SlotsExecuteMsg::Foo => "yay",
+    };
+}
+
+#[test]
+fn derive_slots_query_variants() {
+    let msg = SlotsQueryMsg::Foo;
+
+    // If this compiles we have won.
+    match msg {
+        SlotsQueryMsg::Ownership {}
+        | SlotsQueryMsg::IsOwner {
+            address: _,
+        }
+        | SlotsQueryMsg::PendingOwnership {}
+        | SlotsQueryMsg::TreasuryControllerOwnership {}
+        | SlotsQueryMsg::IsTreasuryControllerOwner {
+            address: _,
+        }
+        | SlotsQueryMsg::PendingTreasuryControllerOwnership {}
+        | SlotsQueryMsg::TreasuryControllerOwnershipHistory {
+            start_after: _,
+            limit: _,
+        }
+        | SlotsQueryMsg::Foo => "yay",
+    };
+}
+
+#[test]
+fn derive_sudo_variants() {
+    let msg = SudoMsg::Foo;
+
+    // If this compiles we have won.
+    match msg {
+        SudoMsg::UpdateOwnership(SudoAction::SetOwner {
+            owner: _,
+        })
+        | SudoMsg::UpdateOwnership(SudoAction::ClearPendingTransfer)
+        | SudoMsg::Foo => "yay",
+    };
+}
+
+#[test]
+fn only_owner_handlers() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let larry = deps.api.addr_make("larry");
+    let jake = deps.api.addr_make("jake");
+
+    cw_ownable::initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str())).unwrap();
+    TREASURY.initialize_owner(&mut deps.storage, &deps.api, Some(jake.as_str())).unwrap();
+
+    // the owner runs the handler
+    let res = execute_foo(deps.as_mut(), env.clone(), message_info(&larry, &[]), 42069);
+    assert_eq!(res, Ok(42069));
+
+    // others are rejected before the handler runs
+    let res = execute_foo(deps.as_mut(), env.clone(), message_info(&jake, &[]), 42069);
+    assert_eq!(res, Err(ContractError::Ownership(OwnershipError::NotOwner)));
+
+    // the check is against the given store
+    let res = execute_bar(deps.as_mut(), env.clone(), &message_info(&jake, &[]));
+    assert_eq!(res, Ok(69));
+
+    let res = execute_bar(deps.as_mut(), env.clone(), &message_info(&larry, &[]));
+    assert_eq!(res, Err(ContractError::Ownership(OwnershipError::NotOwner)));
+
+    // handlers without an `Env` are checked against the stored owner
+    let res = execute_baz(deps.as_mut(), message_info(&larry, &[]));
+    assert_eq!(res, Ok(1337));
+
+    let res = execute_baz(deps.as_mut(), message_info(&jake, &[]));
+    assert_eq!(res, Err(ContractError::Ownership(OwnershipError::NotOwner)));
+
+    // handlers with mutable access record the owner's activity
+    let ownership = TREASURY.get_ownership(&deps.storage, &env.block).unwrap();
+    assert_eq!(ownership.last_activity, Some(Activity::at(&env.block)));
+
+    // read-only handlers only check the owner
+    let res = query_bar(deps.as_ref(), &env, message_info(&jake, &[]));
+    assert_eq!(res, Ok(420));
+
+    let res = query_bar(deps.as_ref(), &env, message_info(&larry, &[]));
+    assert_eq!(res, Err(ContractError::Ownership(OwnershipError::NotOwner)));
+
+    // once the owner's lease has ended, the fallback owner is let through
+    cw_ownable::update_ownership(
+        deps.as_mut(),
+        &env.block,
+        &larry,
+        Action::SetLease {
+            expiry: Expiration::AtHeight(env.block.height + 10),
+            fallback_owner: jake.to_string(),
+        },
+    )
+    .unwrap();
+
+    env.block.height += 10;
+
+    let res = execute_foo(deps.as_mut(), env.clone(), message_info(&larry, &[]), 42069);
+    assert_eq!(res, Err(ContractError::Ownership(OwnershipError::NotOwner)));
+
+    let res = execute_foo(deps.as_mut(), env, message_info(&jake, &[]), 42069);
+    assert_eq!(res, Ok(42069));
+}
+
+#[test]
+fn dispatching_ownership_messages() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let larry = deps.api.addr_make("larry");
+    let jake = deps.api.addr_make("jake");
+    let pumpkin = deps.api.addr_make("pumpkin");
+
+    cw_ownable::initialize_owner(&mut deps.storage, &deps.api, Some(larry.as_str())).unwrap();
+    TREASURY.initialize_owner(&mut deps.storage, &deps.api, Some(jake.as_str())).unwrap();
+
+    let transfer = DispatchExecuteMsg::UpdateOwnership(Action::TransferOwnership {
+        new_owner: pumpkin.to_string(),
+        expiry: None,
+        expires_in: None,
+        delay: None,
+        pubkey: None,
+        price: None,
+    });
+    let handle = |deps: DepsMut, msg: &DispatchExecuteMsg, sender: &Addr| {
+        msg.handle_ownership::<ContractError>(deps, &env, &message_info(sender, &[]))
+    };
+
+    // other messages are left to the contract
+    assert!(handle(deps.as_mut(), &DispatchExecuteMsg::Foo, &larry).is_none());
+
+    // the ownership is updated, with errors converted into the contract's
+    let res = handle(deps.as_mut(), &transfer, &jake).unwrap();
+    assert_eq!(res, Err(ContractError::Ownership(OwnershipError::NotOwner)));
+
+    let res = handle(deps.as_mut(), &transfer, &larry).unwrap().unwrap();
+    assert_eq!(res.events[0].ty, "ownership_transfer_proposed");
+
+    // queries are handled the same way
+    assert!(DispatchQueryMsg::Foo.handle_ownership(deps.as_ref(), &env).is_none());
+
+    let res = DispatchQueryMsg::PendingOwnership {}.handle_ownership(deps.as_ref(), &env).unwrap();
+    let pending: PendingOwnership = from_json(res.unwrap()).unwrap();
+    assert_eq!(pending.pending_owner, Some(pumpkin));
+
+    // the history is queried from the store recording it
+    CREATOR.initialize_owner(&mut deps.storage, &deps.api, Some(jake.as_str())).unwrap();
+    CREATOR.update_ownership(deps.as_mut(), &env.block, &jake, Action::RenounceOwnership).unwrap();
+
+    let res = HistoryDispatchQueryMsg::CreatorHistory {
+        start_after: None,
+        limit: None,
+    }
+    .handle_ownership(deps.as_ref(), &env)
+    .unwrap();
+    let history: Vec<OwnershipChange> = from_json(res.unwrap()).unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].actor, jake);
+
+    // slot variants are dispatched to the slot's store
+    let renounce = SlotsDispatchExecuteMsg::UpdateTreasuryOwnership {
+        action: Action::RenounceOwnership,
+    };
+    let res = renounce
+        .handle_treasury_ownership::<ContractError>(deps.as_mut(), &env, &message_info(&jake, &[]))
+        .unwrap()
+        .unwrap();
+    assert_eq!(res.attributes, [("slot", "treasury")]);
+
+    let res = SlotsDispatchQueryMsg::TreasuryOwnership {}
+        .handle_treasury_ownership(deps.as_ref(), &env)
+        .unwrap();
+    let ownership: Ownership<String> = from_json(res.unwrap()).unwrap();
+    assert_eq!(ownership.owner, None);
+}
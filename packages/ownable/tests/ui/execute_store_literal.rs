@@ -0,0 +1,6 @@
+use cw_ownable::cw_ownable_execute;
+
+#[cw_ownable_execute(dispatch, store = "TREASURY")]
+enum ExecuteMsg {}
+
+fn main() {}
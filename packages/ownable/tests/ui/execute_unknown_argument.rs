@@ -0,0 +1,6 @@
+use cw_ownable::cw_ownable_execute;
+
+#[cw_ownable_execute(owner = "admin")]
+enum ExecuteMsg {}
+
+fn main() {}
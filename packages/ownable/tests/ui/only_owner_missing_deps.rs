@@ -0,0 +1,8 @@
+#[cw_ownable::only_owner]
+fn execute_foo(
+    info: cosmwasm_std::MessageInfo,
+) -> Result<cosmwasm_std::Response, cw_ownable::OwnershipError> {
+    Ok(cosmwasm_std::Response::new().add_attribute("sender", info.sender))
+}
+
+fn main() {}
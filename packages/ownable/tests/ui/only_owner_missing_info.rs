@@ -0,0 +1,9 @@
+#[cw_ownable::only_owner]
+fn execute_foo(
+    _deps: cosmwasm_std::DepsMut,
+    _env: cosmwasm_std::Env,
+) -> Result<cosmwasm_std::Response, cw_ownable::OwnershipError> {
+    Ok(cosmwasm_std::Response::new())
+}
+
+fn main() {}
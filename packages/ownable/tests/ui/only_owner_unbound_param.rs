@@ -0,0 +1,9 @@
+#[cw_ownable::only_owner]
+fn execute_foo(
+    _: cosmwasm_std::DepsMut,
+    _info: cosmwasm_std::MessageInfo,
+) -> Result<cosmwasm_std::Response, cw_ownable::OwnershipError> {
+    Ok(cosmwasm_std::Response::new())
+}
+
+fn main() {}
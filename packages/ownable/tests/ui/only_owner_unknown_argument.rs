@@ -0,0 +1,9 @@
+#[cw_ownable::only_owner(slot = TREASURY)]
+fn execute_foo(
+    _deps: cosmwasm_std::DepsMut,
+    _info: cosmwasm_std::MessageInfo,
+) -> Result<cosmwasm_std::Response, cw_ownable::OwnershipError> {
+    Ok(cosmwasm_std::Response::new())
+}
+
+fn main() {}
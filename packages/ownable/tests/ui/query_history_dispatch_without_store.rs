@@ -0,0 +1,6 @@
+use cw_ownable::cw_ownable_query;
+
+#[cw_ownable_query(history, dispatch)]
+enum QueryMsg {}
+
+fn main() {}
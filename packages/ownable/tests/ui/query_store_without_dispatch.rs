@@ -0,0 +1,8 @@
+use cw_ownable::{cw_ownable_query, OwnershipStore};
+
+const TREASURY: OwnershipStore = OwnershipStore::new("treasury");
+
+#[cw_ownable_query(store = TREASURY)]
+enum QueryMsg {}
+
+fn main() {}